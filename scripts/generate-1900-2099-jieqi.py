@@ -5,7 +5,7 @@ Timezone: GMT+8 (Beijing)
 """
 
 import sys
-from lunar_python import Solar, Lunar
+from lunar_python import Solar, Lunar, LunarYear
 
 def main():
     print("// Auto-generated by generate-1900-2099-jieqi.py. DO NOT EDIT.")
@@ -17,8 +17,10 @@ def main():
     print("\n#[derive(Debug)]")
     print("pub struct BaziYearData {")
     print("    pub year: i32,")
-    print("    pub lunar_new_year: &'static str, // 农历正月初一")
-    print("    pub lichun_time: &'static str,    // 立春交接时间")
+    print("    pub lunar_new_year: &'static str,       // 农历正月初一")
+    print("    pub lichun_time: &'static str,          // 立春交接时间")
+    print("    pub leap_month: u8,                     // 闰月月份 (0 = 无闰月)")
+    print("    pub new_moons: &'static [&'static str], // 各月初一 (含闰月, 末项为次年正月初一)")
     print("    pub jieqi: [JieQiData; 24],")
     print("}")
     
@@ -30,12 +32,22 @@ def main():
         "秋分", "寒露", "霜降", "立冬", "小雪", "大雪"
     ]
 
-    print("\npub const BAZI_YEAR_DATA: [BaziYearData; 200] = [")
+    print("\npub static BAZI_YEAR_DATA: [BaziYearData; 200] = [")
     
     for year in range(1900, 2100):
         # 1. Get Lunar New Year
         lunar_jan1 = Lunar.fromYmd(year, 1, 1)
         lunar_new_year_str = lunar_jan1.getSolar().toYmd()
+
+        # 1b. Month starts (初一) of this lunar year, leap month included in
+        # calendar order, followed by next year's 正月初一 as an end marker.
+        lunar_year = LunarYear.fromYear(year)
+        leap_month = lunar_year.getLeapMonth()
+        new_moons = [
+            Solar.fromJulianDay(m.getFirstJulianDay()).toYmd()
+            for m in lunar_year.getMonthsInYear()
+        ]
+        new_moons.append(Lunar.fromYmd(year + 1, 1, 1).getSolar().toYmd())
         
         # 2. Get JieQi
         # We look for the 24 jieqi associated with this year's cycle.
@@ -55,6 +67,11 @@ def main():
         print(f"        year: {year},")
         print(f"        lunar_new_year: \"{lunar_new_year_str}\",")
         print(f"        lichun_time: \"{lichun_str}\",")
+        print(f"        leap_month: {leap_month},")
+        print(f"        new_moons: &[")
+        for d in new_moons:
+            print(f"            \"{d}\",")
+        print(f"        ],")
         print(f"        jieqi: [")
         
         # Extract the 24 terms in astronomical order starting from Winter Solstice (often used as start of calculation)
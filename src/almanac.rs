@@ -0,0 +1,786 @@
+use crate::bazi_calc::{DAY_PILLAR_BASE, term_on, try_get_pillars};
+use crate::error::LunarError;
+use crate::ganzhi::{EarthlyBranch, GanZhi};
+use crate::lunar_date::{LunarDate, try_to_lunar_date};
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The twelve day officers (建除十二神), in cycle order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DayOfficer {
+    /// 建
+    Jian,
+    /// 除
+    Chu,
+    /// 满
+    Man,
+    /// 平
+    Ping,
+    /// 定
+    Ding,
+    /// 执
+    Zhi,
+    /// 破
+    Po,
+    /// 危
+    Wei,
+    /// 成
+    Cheng,
+    /// 收
+    Shou,
+    /// 开
+    Kai,
+    /// 闭
+    Bi,
+}
+
+impl DayOfficer {
+    pub const ALL: [DayOfficer; 12] = [
+        DayOfficer::Jian,
+        DayOfficer::Chu,
+        DayOfficer::Man,
+        DayOfficer::Ping,
+        DayOfficer::Ding,
+        DayOfficer::Zhi,
+        DayOfficer::Po,
+        DayOfficer::Wei,
+        DayOfficer::Cheng,
+        DayOfficer::Shou,
+        DayOfficer::Kai,
+        DayOfficer::Bi,
+    ];
+
+    /// Officer of a day: 建 falls on the day whose branch equals the month
+    /// branch, and each following branch takes the next officer.
+    pub fn of(month_branch: EarthlyBranch, day_branch: EarthlyBranch) -> DayOfficer {
+        Self::ALL[(day_branch.index() + 12 - month_branch.index()) % 12]
+    }
+
+    /// Officer by its character, e.g. `成`.
+    pub fn from_name(name: &str) -> Option<DayOfficer> {
+        Self::ALL.into_iter().find(|o| o.as_str() == name)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DayOfficer::Jian => "建",
+            DayOfficer::Chu => "除",
+            DayOfficer::Man => "满",
+            DayOfficer::Ping => "平",
+            DayOfficer::Ding => "定",
+            DayOfficer::Zhi => "执",
+            DayOfficer::Po => "破",
+            DayOfficer::Wei => "危",
+            DayOfficer::Cheng => "成",
+            DayOfficer::Shou => "收",
+            DayOfficer::Kai => "开",
+            DayOfficer::Bi => "闭",
+        }
+    }
+}
+
+impl fmt::Display for DayOfficer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The twenty-eight lunar mansions (二十八宿), in order from 角.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mansion {
+    /// 角木蛟
+    Horn,
+    /// 亢金龙
+    Neck,
+    /// 氐土貉
+    Root,
+    /// 房日兔
+    Room,
+    /// 心月狐
+    Heart,
+    /// 尾火虎
+    Tail,
+    /// 箕水豹
+    WinnowingBasket,
+    /// 斗木獬
+    Dipper,
+    /// 牛金牛
+    Ox,
+    /// 女土蝠
+    Girl,
+    /// 虚日鼠
+    Emptiness,
+    /// 危月燕
+    Rooftop,
+    /// 室火猪
+    Encampment,
+    /// 壁水貐
+    Wall,
+    /// 奎木狼
+    Legs,
+    /// 娄金狗
+    Bond,
+    /// 胃土雉
+    Stomach,
+    /// 昴日鸡
+    HairyHead,
+    /// 毕月乌
+    Net,
+    /// 觜火猴
+    TurtleBeak,
+    /// 参水猿
+    ThreeStars,
+    /// 井木犴
+    Well,
+    /// 鬼金羊
+    Ghost,
+    /// 柳土獐
+    Willow,
+    /// 星日马
+    Star,
+    /// 张月鹿
+    ExtendedNet,
+    /// 翼火蛇
+    Wings,
+    /// 轸水蚓
+    Chariot,
+}
+
+const MANSION_NAMES: [&str; 28] = [
+    "角木蛟",
+    "亢金龙",
+    "氐土貉",
+    "房日兔",
+    "心月狐",
+    "尾火虎",
+    "箕水豹",
+    "斗木獬",
+    "牛金牛",
+    "女土蝠",
+    "虚日鼠",
+    "危月燕",
+    "室火猪",
+    "壁水貐",
+    "奎木狼",
+    "娄金狗",
+    "胃土雉",
+    "昴日鸡",
+    "毕月乌",
+    "觜火猴",
+    "参水猿",
+    "井木犴",
+    "鬼金羊",
+    "柳土獐",
+    "星日马",
+    "张月鹿",
+    "翼火蛇",
+    "轸水蚓",
+];
+
+/// Mansion of [`DAY_PILLAR_BASE`] (1900-01-01, a Monday): 心.
+///
+/// The mansions run one per day without a break, and the luminary of each
+/// (日月火水木金土) matches the weekday, so 房虚昴星 always fall on Sundays.
+const MANSION_BASE_INDEX: i64 = 4;
+
+impl Mansion {
+    pub const ALL: [Mansion; 28] = [
+        Mansion::Horn,
+        Mansion::Neck,
+        Mansion::Root,
+        Mansion::Room,
+        Mansion::Heart,
+        Mansion::Tail,
+        Mansion::WinnowingBasket,
+        Mansion::Dipper,
+        Mansion::Ox,
+        Mansion::Girl,
+        Mansion::Emptiness,
+        Mansion::Rooftop,
+        Mansion::Encampment,
+        Mansion::Wall,
+        Mansion::Legs,
+        Mansion::Bond,
+        Mansion::Stomach,
+        Mansion::HairyHead,
+        Mansion::Net,
+        Mansion::TurtleBeak,
+        Mansion::ThreeStars,
+        Mansion::Well,
+        Mansion::Ghost,
+        Mansion::Willow,
+        Mansion::Star,
+        Mansion::ExtendedNet,
+        Mansion::Wings,
+        Mansion::Chariot,
+    ];
+
+    /// Mansion in charge of a civil date.
+    pub fn of(date: NaiveDate) -> Mansion {
+        let days = (date - DAY_PILLAR_BASE).num_days();
+        Self::ALL[(days + MANSION_BASE_INDEX).rem_euclid(28) as usize]
+    }
+
+    /// Single-character name, e.g. `角`.
+    pub fn as_str(self) -> &'static str {
+        let name = self.full_name();
+        &name[..name.chars().next().map_or(0, char::len_utf8)]
+    }
+
+    /// Name with luminary and animal, e.g. `角木蛟`.
+    pub fn full_name(self) -> &'static str {
+        MANSION_NAMES[self as usize]
+    }
+}
+
+impl fmt::Display for Mansion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.full_name())
+    }
+}
+
+/// Compass direction of the day's 煞.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::North => "北",
+            Direction::East => "东",
+            Direction::South => "南",
+            Direction::West => "西",
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 冲煞 of a day: the pillar it clashes and the direction of its 煞.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayClash {
+    /// Clashed pillar: stem four places on, opposite branch (甲子日 冲 戊午).
+    pub pillar: GanZhi,
+    /// 申子辰 days 煞南, 寅午戌 煞北, 亥卯未 煞西, 巳酉丑 煞东.
+    pub sha: Direction,
+}
+
+impl DayClash {
+    pub fn of(day: GanZhi) -> DayClash {
+        let pillar = GanZhi::from_stem_branch(day.stem() + 4, day.branch() + 6)
+            .expect("stem and branch shift keep the same parity");
+        let sha = match day.branch().index() % 4 {
+            0 => Direction::South,
+            1 => Direction::East,
+            2 => Direction::North,
+            _ => Direction::West,
+        };
+        DayClash { pillar, sha }
+    }
+
+    /// Zodiac animal of the clashed branch, e.g. `马`.
+    pub fn zodiac(&self) -> &'static str {
+        self.pillar.branch().zodiac()
+    }
+}
+
+impl fmt::Display for DayClash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "冲{}({}) 煞{}", self.zodiac(), self.pillar, self.sha)
+    }
+}
+
+/// Activities listed under 宜 and 忌.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activity {
+    /// 祭祀
+    Sacrifice,
+    /// 祈福
+    Prayer,
+    /// 嫁娶
+    Marriage,
+    /// 纳采
+    Betrothal,
+    /// 出行
+    Travel,
+    /// 移徙
+    Moving,
+    /// 上任
+    TakeOffice,
+    /// 入学
+    Study,
+    /// 开市
+    OpenBusiness,
+    /// 交易
+    Trade,
+    /// 纳财
+    CollectWealth,
+    /// 修造
+    Repair,
+    /// 动土
+    Groundbreaking,
+    /// 栽种
+    Planting,
+    /// 扫舍
+    Cleaning,
+    /// 求医
+    Medical,
+    /// 破屋
+    Demolition,
+    /// 词讼
+    Lawsuit,
+    /// 安葬
+    Burial,
+}
+
+impl Activity {
+    pub const ALL: [Activity; 19] = [
+        Activity::Sacrifice,
+        Activity::Prayer,
+        Activity::Marriage,
+        Activity::Betrothal,
+        Activity::Travel,
+        Activity::Moving,
+        Activity::TakeOffice,
+        Activity::Study,
+        Activity::OpenBusiness,
+        Activity::Trade,
+        Activity::CollectWealth,
+        Activity::Repair,
+        Activity::Groundbreaking,
+        Activity::Planting,
+        Activity::Cleaning,
+        Activity::Medical,
+        Activity::Demolition,
+        Activity::Lawsuit,
+        Activity::Burial,
+    ];
+
+    /// Activity by its almanac name, e.g. `嫁娶`.
+    pub fn from_name(name: &str) -> Option<Activity> {
+        Self::ALL.into_iter().find(|a| a.as_str() == name)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Activity::Sacrifice => "祭祀",
+            Activity::Prayer => "祈福",
+            Activity::Marriage => "嫁娶",
+            Activity::Betrothal => "纳采",
+            Activity::Travel => "出行",
+            Activity::Moving => "移徙",
+            Activity::TakeOffice => "上任",
+            Activity::Study => "入学",
+            Activity::OpenBusiness => "开市",
+            Activity::Trade => "交易",
+            Activity::CollectWealth => "纳财",
+            Activity::Repair => "修造",
+            Activity::Groundbreaking => "动土",
+            Activity::Planting => "栽种",
+            Activity::Cleaning => "扫舍",
+            Activity::Medical => "求医",
+            Activity::Demolition => "破屋",
+            Activity::Lawsuit => "词讼",
+            Activity::Burial => "安葬",
+        }
+    }
+}
+
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// When an [`AlmanacRule`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlmanacCondition {
+    Officer(DayOfficer),
+    Mansion(Mansion),
+    /// 四绝日: the day before 立春, 立夏, 立秋 or 立冬.
+    SiJue,
+    /// 四离日: the day before 春分, 夏至, 秋分 or 冬至.
+    SiLi,
+}
+
+/// One row of the 宜忌 table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AlmanacRule {
+    pub condition: AlmanacCondition,
+    /// Activities added to 宜.
+    pub good: &'static [Activity],
+    /// Activities added to 忌; these win over any rule listing them as 宜.
+    pub bad: &'static [Activity],
+}
+
+const fn officer(
+    officer: DayOfficer,
+    good: &'static [Activity],
+    bad: &'static [Activity],
+) -> AlmanacRule {
+    AlmanacRule {
+        condition: AlmanacCondition::Officer(officer),
+        good,
+        bad,
+    }
+}
+
+const fn mansion(
+    mansion: Mansion,
+    good: &'static [Activity],
+    bad: &'static [Activity],
+) -> AlmanacRule {
+    AlmanacRule {
+        condition: AlmanacCondition::Mansion(mansion),
+        good,
+        bad,
+    }
+}
+
+/// Default 宜忌 rules: one row per day officer, the mansions with a
+/// well-known effect on weddings, building or burial, and 四绝/四离 days.
+pub const ALMANAC_RULES: &[AlmanacRule] = {
+    use Activity::*;
+    &[
+        officer(
+            DayOfficer::Jian,
+            &[Travel, TakeOffice, Prayer],
+            &[Groundbreaking, Burial],
+        ),
+        officer(
+            DayOfficer::Chu,
+            &[Sacrifice, Medical, Cleaning],
+            &[Marriage, Travel],
+        ),
+        officer(
+            DayOfficer::Man,
+            &[Sacrifice, Prayer, OpenBusiness, CollectWealth],
+            &[TakeOffice, Planting, Medical],
+        ),
+        officer(DayOfficer::Ping, &[Repair, Cleaning], &[Planting]),
+        officer(
+            DayOfficer::Ding,
+            &[Sacrifice, Marriage, Betrothal, Trade],
+            &[Lawsuit, Travel, Medical],
+        ),
+        officer(
+            DayOfficer::Zhi,
+            &[Sacrifice, Betrothal, Repair],
+            &[Travel, Moving, OpenBusiness],
+        ),
+        officer(
+            DayOfficer::Po,
+            &[Demolition, Medical],
+            &[
+                Marriage,
+                Betrothal,
+                Travel,
+                Moving,
+                OpenBusiness,
+                Trade,
+                Groundbreaking,
+                Burial,
+            ],
+        ),
+        officer(DayOfficer::Wei, &[Sacrifice, Prayer], &[Travel]),
+        officer(
+            DayOfficer::Cheng,
+            &[Marriage, Betrothal, OpenBusiness, Trade, Study, TakeOffice],
+            &[Lawsuit],
+        ),
+        officer(
+            DayOfficer::Shou,
+            &[CollectWealth, Trade, Planting],
+            &[Travel, Burial],
+        ),
+        officer(
+            DayOfficer::Kai,
+            &[OpenBusiness, Trade, Study, Marriage, Travel, TakeOffice],
+            &[Burial, Groundbreaking],
+        ),
+        officer(
+            DayOfficer::Bi,
+            &[Burial, Repair],
+            &[Travel, OpenBusiness, Medical, TakeOffice],
+        ),
+        mansion(Mansion::Horn, &[Marriage, Groundbreaking], &[]),
+        mansion(Mansion::Neck, &[], &[Marriage, Burial]),
+        mansion(Mansion::Ghost, &[Burial], &[Marriage, Groundbreaking]),
+        AlmanacRule {
+            condition: AlmanacCondition::SiJue,
+            good: &[],
+            bad: &[Marriage, Travel, Moving, OpenBusiness],
+        },
+        AlmanacRule {
+            condition: AlmanacCondition::SiLi,
+            good: &[],
+            bad: &[Marriage, Travel, Moving, OpenBusiness],
+        },
+    ]
+};
+
+/// One day of the almanac (黄历).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AlmanacDay {
+    pub date: NaiveDate,
+    /// `None` before lunar 1900 正月初一 (1900-01-31).
+    pub lunar: Option<LunarDate>,
+    /// Year, month and day pillars in force at the end of the day, so a
+    /// LiChun or Jie day already belongs to the new year or month.
+    pub year: GanZhi,
+    pub month: GanZhi,
+    pub day: GanZhi,
+    pub officer: DayOfficer,
+    pub mansion: Mansion,
+    pub clash: DayClash,
+    /// Solar term falling on this day, e.g. `立春`.
+    pub term: Option<&'static str>,
+    /// 四绝日: the next day is 立春, 立夏, 立秋 or 立冬.
+    pub si_jue: bool,
+    /// 四离日: the next day is 春分, 夏至, 秋分 or 冬至.
+    pub si_li: bool,
+    /// 宜, in [`Activity`] order.
+    pub good: Vec<Activity>,
+    /// 忌, in [`Activity`] order.
+    pub bad: Vec<Activity>,
+}
+
+impl fmt::Display for AlmanacDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |activities: &[Activity]| {
+            if activities.is_empty() {
+                "—".to_string()
+            } else {
+                let names: Vec<&str> = activities.iter().map(|a| a.as_str()).collect();
+                names.join(" ")
+            }
+        };
+        write!(f, "{}", self.date)?;
+        if let Some(lunar) = &self.lunar {
+            write!(f, " 农历{}", lunar)?;
+        }
+        write!(f, " {}年 {}月 {}日", self.year, self.month, self.day)?;
+        if let Some(term) = self.term {
+            write!(f, " {}", term)?;
+        }
+        write!(
+            f,
+            "\n{}日 | {} | {}",
+            self.officer, self.mansion, self.clash
+        )?;
+        if self.si_jue {
+            f.write_str(" | 四绝")?;
+        }
+        if self.si_li {
+            f.write_str(" | 四离")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "宜: {}", list(&self.good))?;
+        write!(f, "忌: {}", list(&self.bad))
+    }
+}
+
+/// Almanac entry for a civil date (UTC+8) with the default [`ALMANAC_RULES`].
+pub fn almanac(date: NaiveDate) -> Option<AlmanacDay> {
+    try_almanac(date).ok()
+}
+
+/// Like [`almanac`], but explains why the date is unavailable.
+pub fn try_almanac(date: NaiveDate) -> Result<AlmanacDay, LunarError> {
+    try_almanac_with(date, ALMANAC_RULES)
+}
+
+/// Almanac entry for a civil date (UTC+8) with a custom 宜忌 rule table.
+pub fn try_almanac_with(date: NaiveDate, rules: &[AlmanacRule]) -> Result<AlmanacDay, LunarError> {
+    let end_of_day = date.and_hms_opt(23, 59, 59).expect("valid time");
+    let pillars = try_get_pillars(end_of_day)?;
+    let officer = DayOfficer::of(pillars.month.branch(), pillars.day.branch());
+    let mansion = Mansion::of(date);
+    let next_term = match date.succ_opt() {
+        Some(next) => term_on(next)?,
+        None => None,
+    };
+    let si_jue = matches!(next_term, Some("立春" | "立夏" | "立秋" | "立冬"));
+    let si_li = matches!(next_term, Some("春分" | "夏至" | "秋分" | "冬至"));
+
+    let mut good = BTreeSet::new();
+    let mut bad = BTreeSet::new();
+    for rule in rules {
+        let applies = match rule.condition {
+            AlmanacCondition::Officer(o) => o == officer,
+            AlmanacCondition::Mansion(m) => m == mansion,
+            AlmanacCondition::SiJue => si_jue,
+            AlmanacCondition::SiLi => si_li,
+        };
+        if applies {
+            good.extend(rule.good);
+            bad.extend(rule.bad);
+        }
+    }
+
+    Ok(AlmanacDay {
+        date,
+        lunar: try_to_lunar_date(date).ok(),
+        year: pillars.year,
+        month: pillars.month,
+        day: pillars.day,
+        officer,
+        mansion,
+        clash: DayClash::of(pillars.day),
+        term: term_on(date)?,
+        si_jue,
+        si_li,
+        good: good.difference(&bad).copied().collect(),
+        bad: bad.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Weekday};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn spring_festival_2024() {
+        let day = almanac(date(2024, 2, 10)).unwrap();
+        assert_eq!(day.day, "甲辰");
+        assert_eq!(day.month, "丙寅");
+        assert_eq!(day.year, "甲辰");
+        assert_eq!(day.officer, DayOfficer::Man);
+        assert_eq!(day.mansion, Mansion::Root);
+        assert_eq!(day.clash.to_string(), "冲狗(戊戌) 煞南");
+        assert_eq!(day.lunar.unwrap().to_string(), "2024年正月初一");
+        assert!(day.good.contains(&Activity::OpenBusiness));
+        assert!(day.bad.contains(&Activity::Medical));
+    }
+
+    #[test]
+    fn officer_repeats_on_jie_day() {
+        // 立春 2024-02-04: the day before is the last 丑 month day.
+        let before = almanac(date(2024, 2, 3)).unwrap();
+        let jie_day = almanac(date(2024, 2, 4)).unwrap();
+        assert_eq!(before.month, "乙丑");
+        assert_eq!(jie_day.month, "丙寅");
+        assert_eq!(before.officer, DayOfficer::Cheng);
+        assert_eq!(jie_day.officer, DayOfficer::Cheng);
+        let after = almanac(date(2024, 2, 5)).unwrap();
+        assert_eq!(after.officer, DayOfficer::Shou);
+    }
+
+    #[test]
+    fn si_jue_and_si_li_days() {
+        let eve = almanac(date(2024, 2, 3)).unwrap();
+        assert!(eve.si_jue && !eve.si_li);
+        assert!(eve.bad.contains(&Activity::Marriage));
+        assert!(!eve.good.contains(&Activity::Marriage));
+        assert_eq!(almanac(date(2024, 2, 4)).unwrap().term, Some("立春"));
+
+        // 冬至 2024-12-21 comes from the next table row.
+        let winter_eve = almanac(date(2024, 12, 20)).unwrap();
+        assert!(winter_eve.si_li && !winter_eve.si_jue);
+        assert_eq!(almanac(date(2024, 12, 21)).unwrap().term, Some("冬至"));
+        assert!(!almanac(date(2024, 12, 22)).unwrap().si_li);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for officer in DayOfficer::ALL {
+            assert_eq!(DayOfficer::from_name(officer.as_str()), Some(officer));
+        }
+        for activity in Activity::ALL {
+            assert_eq!(Activity::from_name(activity.as_str()), Some(activity));
+        }
+        assert_eq!(Activity::from_name("飞行"), None);
+    }
+
+    #[test]
+    fn jian_on_month_branch_and_po_on_clash() {
+        assert_eq!(
+            DayOfficer::of(EarthlyBranch::Yin, EarthlyBranch::Yin),
+            DayOfficer::Jian
+        );
+        assert_eq!(
+            DayOfficer::of(EarthlyBranch::Yin, EarthlyBranch::Shen),
+            DayOfficer::Po
+        );
+        assert_eq!(
+            DayOfficer::of(EarthlyBranch::Zi, EarthlyBranch::Hai),
+            DayOfficer::Bi
+        );
+    }
+
+    #[test]
+    fn mansion_luminary_matches_weekday() {
+        let sunday = ["房", "虚", "昴", "星"];
+        let mut d = date(1900, 1, 1);
+        while d < date(1900, 3, 1) {
+            let mansion = Mansion::of(d);
+            assert_eq!(
+                sunday.contains(&mansion.as_str()),
+                d.weekday() == Weekday::Sun,
+                "{d} {mansion}"
+            );
+            d = d.succ_opt().unwrap();
+        }
+        assert_eq!(Mansion::of(date(2000, 1, 1)), Mansion::Stomach);
+    }
+
+    #[test]
+    fn clash_and_sha_by_branch_group() {
+        let clash = DayClash::of("甲子".parse().unwrap());
+        assert_eq!(clash.to_string(), "冲马(戊午) 煞南");
+        assert_eq!(DayClash::of("丙午".parse().unwrap()).sha, Direction::North);
+        assert_eq!(DayClash::of("乙卯".parse().unwrap()).sha, Direction::West);
+        assert_eq!(DayClash::of("己酉".parse().unwrap()).sha, Direction::East);
+    }
+
+    #[test]
+    fn bad_wins_over_good_and_custom_rules() {
+        // 2024-02-10 is 满 in 氐; add a rule that forbids what 满 favors.
+        let rules = [
+            ALMANAC_RULES[2],
+            mansion(
+                Mansion::Root,
+                &[Activity::Travel],
+                &[Activity::OpenBusiness],
+            ),
+        ];
+        let day = try_almanac_with(date(2024, 2, 10), &rules).unwrap();
+        assert!(day.good.contains(&Activity::Travel));
+        assert!(!day.good.contains(&Activity::OpenBusiness));
+        assert!(day.bad.contains(&Activity::OpenBusiness));
+    }
+
+    #[test]
+    fn table_edges() {
+        let first = almanac(date(1900, 1, 1)).unwrap();
+        assert_eq!(first.day, "甲戌");
+        assert_eq!(first.lunar, None);
+        assert!(matches!(
+            try_almanac(date(2100, 1, 1)),
+            Err(LunarError::OutOfRange { .. })
+        ));
+    }
+}
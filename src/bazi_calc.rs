@@ -1,17 +1,20 @@
 use crate::data::BAZI_YEAR_DATA;
-use crate::table_lookup::{
-    BRANCHES, MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR, STEMS, get_cycle_index,
-};
-use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use crate::error::LunarError;
+use crate::ganzhi::{EarthlyBranch, GanZhi, ParseGanZhiError, parse_error};
+use crate::solar_time::SolarTimeCorrection;
+use crate::table_lookup::{get_cycle_index, table_index};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use std::str::FromStr;
 use std::sync::OnceLock;
 
 /// Pillars of a specific date and time.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaziPillars {
-    pub year: String,
-    pub month: String,
-    pub day: String,
-    pub hour: String,
+    pub year: GanZhi,
+    pub month: GanZhi,
+    pub day: GanZhi,
+    pub hour: GanZhi,
 }
 
 impl std::fmt::Display for BaziPillars {
@@ -24,8 +27,86 @@ impl std::fmt::Display for BaziPillars {
     }
 }
 
+/// Parses four pillars separated by whitespace, `乙丑 庚辰 庚寅 庚辰`, or the
+/// `Display` form `乙丑年 庚辰月 庚寅日 庚辰时`.
+impl FromStr for BaziPillars {
+    type Err = ParseGanZhiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let [year, month, day, hour] = parts[..] else {
+            return Err(parse_error(s));
+        };
+        let pillar = |part: &str, suffix: &str| part.strip_suffix(suffix).unwrap_or(part).parse();
+        Ok(BaziPillars {
+            year: pillar(year, "年")?,
+            month: pillar(month, "月")?,
+            day: pillar(day, "日")?,
+            hour: pillar(hour, "时")?,
+        })
+    }
+}
+
+/// Day-boundary convention for the 子 hour (23:00-00:59).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZiHourRule {
+    /// Civil-date day pillar: the day changes at 00:00 and 23:00-23:59 counts
+    /// its 子 hour stem from the civil day (辛 day 23:00 is 戊子).
+    #[default]
+    DayChangesAtMidnight,
+    /// 早子时/晚子时: the day changes at 00:00, but 23:00-23:59 (晚子时) takes
+    /// the next day's 子 hour stem (辛 day 23:00 is 庚子).
+    LateZiNextDayStem,
+    /// 子初换日: the day changes at 23:00, so 23:00-23:59 takes the next day's
+    /// day pillar and 子 hour.
+    DayChangesAt23,
+}
+
+impl ZiHourRule {
+    pub const ALL: [ZiHourRule; 3] = [
+        ZiHourRule::DayChangesAtMidnight,
+        ZiHourRule::LateZiNextDayStem,
+        ZiHourRule::DayChangesAt23,
+    ];
+
+    /// Rule by its CLI/JSON name, e.g. `late-zi`.
+    pub fn from_name(name: &str) -> Option<ZiHourRule> {
+        Self::ALL.into_iter().find(|rule| rule.as_str() == name)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ZiHourRule::DayChangesAtMidnight => "midnight",
+            ZiHourRule::LateZiNextDayStem => "late-zi",
+            ZiHourRule::DayChangesAt23 => "23",
+        }
+    }
+
+    /// One-line description of the convention.
+    pub fn description(self) -> &'static str {
+        match self {
+            ZiHourRule::DayChangesAtMidnight => "civil-date day pillar, day changes at 00:00",
+            ZiHourRule::LateZiNextDayStem => {
+                "day changes at 00:00, 23:00-23:59 takes the next day's 子 stem (早子时/晚子时)"
+            }
+            ZiHourRule::DayChangesAt23 => "day changes at 23:00 (子初换日)",
+        }
+    }
+}
+
+/// Conventions applied by [`get_pillars_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaziOptions {
+    pub zi_hour_rule: ZiHourRule,
+    /// Use true solar time for the day and hour pillars.
+    pub solar_time: Option<SolarTimeCorrection>,
+}
+
 /// Information about a specific time point relative to solar terms.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct JieQiContext {
     pub prev_name: &'static str,
     pub prev_time: NaiveDateTime,
@@ -69,54 +150,57 @@ struct ParsedYearData {
     jieqi: [ParsedJieQi; 24],
 }
 
-static PARSED_YEAR_TABLE: OnceLock<Option<Vec<ParsedYearData>>> = OnceLock::new();
+static PARSED_YEAR_TABLE: OnceLock<Result<Vec<ParsedYearData>, LunarError>> = OnceLock::new();
+
+/// UTC offset of every `BAZI_YEAR_DATA` timestamp (GMT+8).
+pub const TABLE_UTC_OFFSET_SECONDS: i32 = 8 * 3600;
+
+/// Day pillar base: 1900-01-01 is 甲戌 (index 10).
+pub(crate) const DAY_PILLAR_BASE: NaiveDate =
+    NaiveDate::from_ymd_opt(1900, 1, 1).expect("valid base date");
 
 fn parse_ts(value: &str) -> Option<NaiveDateTime> {
     NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").ok()
 }
 
-fn parsed_year_table() -> Option<&'static [ParsedYearData]> {
+fn parsed_year_table() -> Result<&'static [ParsedYearData], LunarError> {
     PARSED_YEAR_TABLE
         .get_or_init(|| {
             let mut parsed = Vec::with_capacity(BAZI_YEAR_DATA.len());
             for row in BAZI_YEAR_DATA.iter() {
-                let lichun_time = parse_ts(row.lichun_time)?;
+                let corrupt = |field| LunarError::CorruptTable {
+                    year: row.year,
+                    field,
+                };
+                let lichun_time =
+                    parse_ts(row.lichun_time).ok_or_else(|| corrupt("lichun_time"))?;
 
                 let mut jieqi = Vec::with_capacity(row.jieqi.len());
                 for jq in row.jieqi.iter() {
-                    let time = parse_ts(jq.time)?;
+                    let time = parse_ts(jq.time).ok_or_else(|| corrupt("jieqi"))?;
                     jieqi.push(ParsedJieQi {
                         name: jq.name,
                         time,
                     });
                 }
 
-                let jieqi: [ParsedJieQi; 24] = jieqi.try_into().ok()?;
+                let jieqi: [ParsedJieQi; 24] = jieqi.try_into().map_err(|_| corrupt("jieqi"))?;
                 parsed.push(ParsedYearData {
                     year: row.year,
                     lichun_time,
                     jieqi,
                 });
             }
-            Some(parsed)
+            Ok(parsed)
         })
         .as_deref()
+        .map_err(Clone::clone)
 }
 
-fn parsed_year_data(year: i32) -> Option<&'static ParsedYearData> {
-    if !(MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&year) {
-        return None;
-    }
-    let idx = (year - MIN_SUPPORTED_YEAR) as usize;
-    let row = parsed_year_table()?.get(idx)?;
+fn parsed_year_data(year: i32) -> Result<&'static ParsedYearData, LunarError> {
+    let row = &parsed_year_table()?[table_index(year)?];
     debug_assert_eq!(row.year, year);
-    Some(row)
-}
-
-/// Helper to get formatted stem-branch string.
-fn format_gz(index: usize) -> String {
-    let idx = index % 60;
-    format!("{}{}", STEMS[idx % 10], BRANCHES[idx % 12])
+    Ok(row)
 }
 
 fn normalize_jie_name(name: &'static str) -> &'static str {
@@ -141,14 +225,142 @@ fn month_index_from_jie(name: &str) -> Option<usize> {
     }
 }
 
+/// Previous and next Jie as `(name, time)`.
+pub(crate) type JieBounds = (
+    Option<(&'static str, NaiveDateTime)>,
+    Option<(&'static str, NaiveDateTime)>,
+);
+
+/// The last Jie (节, the twelve month-opening terms) at or before
+/// `boundary_time` and the first one after it, both in the table's UTC+8 frame.
+///
+/// Either side is `None` past the edge of the table.
+pub(crate) fn surrounding_jie(boundary_time: NaiveDateTime) -> Result<JieBounds, LunarError> {
+    let year = boundary_time.year();
+    // Only the neighbouring years may fall off the table.
+    parsed_year_data(year)?;
+    let mut prev: Option<(&'static str, NaiveDateTime)> = None;
+    let mut next: Option<(&'static str, NaiveDateTime)> = None;
+    for y in [year - 1, year, year + 1] {
+        if let Ok(data) = parsed_year_data(y) {
+            for term in &data.jieqi {
+                let name = normalize_jie_name(term.name);
+                if month_index_from_jie(name).is_none() {
+                    continue;
+                }
+                if term.time <= boundary_time {
+                    if prev.as_ref().map(|(_, t)| term.time > *t).unwrap_or(true) {
+                        prev = Some((name, term.time));
+                    }
+                } else if next.as_ref().map(|(_, t)| term.time < *t).unwrap_or(true) {
+                    next = Some((name, term.time));
+                }
+            }
+        }
+    }
+    Ok((prev, next))
+}
+
+/// The twelve Jie of the solar year that starts at `year`'s LiChun, in order
+/// from 立春 to 小寒, followed by the next year's 立春.
+pub(crate) fn solar_year_jie(year: i32) -> Result<[(&'static str, NaiveDateTime); 13], LunarError> {
+    let start = parsed_year_data(year)?.lichun_time;
+    let end = parsed_year_data(year + 1)?.lichun_time;
+    let mut terms: Vec<(&'static str, NaiveDateTime)> = [year, year + 1]
+        .iter()
+        .flat_map(|&y| {
+            parsed_year_data(y)
+                .map(|data| &data.jieqi[..])
+                .unwrap_or(&[])
+        })
+        .map(|term| (normalize_jie_name(term.name), term.time))
+        .filter(|(name, time)| month_index_from_jie(name).is_some() && (start..=end).contains(time))
+        .collect();
+    terms.sort_by_key(|(_, time)| *time);
+    terms.dedup();
+    terms.try_into().map_err(|_| LunarError::CorruptTable {
+        year,
+        field: "jieqi",
+    })
+}
+
+/// Name of the solar term whose instant (UTC+8) falls on `date`, if any.
+///
+/// Like [`surrounding_jie`], rows past the edge of the table are skipped, so
+/// late-December terms of the last table year are not found.
+pub(crate) fn term_on(date: NaiveDate) -> Result<Option<&'static str>, LunarError> {
+    parsed_year_table()?;
+    for y in [date.year(), date.year() + 1] {
+        if let Ok(data) = parsed_year_data(y)
+            && let Some(term) = data.jieqi.iter().find(|term| term.time.date() == date)
+        {
+            return Ok(Some(normalize_jie_name(term.name)));
+        }
+    }
+    Ok(None)
+}
+
+/// Instant (UTC+8) of the solar term `name` in Gregorian `year`.
+///
+/// 大雪 and 冬至 of `year` live in the next table row, so they are available
+/// for 1899 and out of range for the last table year.
+pub(crate) fn term_time(year: i32, name: &str) -> Result<NaiveDateTime, LunarError> {
+    let mut missing = LunarError::CorruptTable {
+        year,
+        field: "jieqi",
+    };
+    for y in year..=year.saturating_add(1) {
+        match parsed_year_data(y) {
+            Ok(data) => {
+                if let Some(term) = data
+                    .jieqi
+                    .iter()
+                    .find(|term| normalize_jie_name(term.name) == name && term.time.year() == year)
+                {
+                    return Ok(term.time);
+                }
+            }
+            Err(e) => missing = e,
+        }
+    }
+    Err(missing)
+}
+
+/// Day pillar of a civil date, counted from 1900-01-01 甲戌.
+pub(crate) fn day_pillar(date: NaiveDate) -> GanZhi {
+    let diff_days = (date - DAY_PILLAR_BASE).num_days();
+    GanZhi::from_index(10) + (diff_days.rem_euclid(60) as i32)
+}
+
+/// Month pillar of the `month_idx`-th Jie month (0 = 寅) in a year with pillar `year_gz`.
+///
+/// Month Stem: (Year Stem index * 2 + Month index + 2) % 10. The 寅 month of
+/// 甲/己 years is 丙寅 (index 2) and each later stem pair starts 12 further on.
+pub(crate) fn month_pillar(year_gz: GanZhi, month_idx: usize) -> GanZhi {
+    GanZhi::from_index(2 + 12 * (year_gz.stem().index() % 5) + month_idx)
+}
+
+/// Converts a zoned datetime into the table's UTC+8 frame.
+pub fn to_table_time<Tz: TimeZone>(dt: &DateTime<Tz>) -> NaiveDateTime {
+    let table_zone = FixedOffset::east_opt(TABLE_UTC_OFFSET_SECONDS).expect("valid UTC+8 offset");
+    dt.with_timezone(&table_zone).naive_local()
+}
+
 /// Returns the surrounding JieQi (Solar Terms) for a given timestamp.
 pub fn get_jieqi_context(target_time: NaiveDateTime) -> Option<JieQiContext> {
+    try_get_jieqi_context(target_time).ok()
+}
+
+/// Like [`get_jieqi_context`], but explains why no context is available.
+pub fn try_get_jieqi_context(target_time: NaiveDateTime) -> Result<JieQiContext, LunarError> {
     let year = target_time.date().year();
+    // Only the neighbouring years may fall off the table.
+    parsed_year_data(year)?;
     let mut prev: Option<(&'static str, NaiveDateTime)> = None;
     let mut next: Option<(&'static str, NaiveDateTime)> = None;
 
     for y in [year - 1, year, year + 1] {
-        if let Some(data) = parsed_year_data(y) {
+        if let Ok(data) = parsed_year_data(y) {
             for term in &data.jieqi {
                 if term.time <= target_time {
                     if prev.as_ref().map(|(_, t)| term.time > *t).unwrap_or(true) {
@@ -161,10 +373,11 @@ pub fn get_jieqi_context(target_time: NaiveDateTime) -> Option<JieQiContext> {
         }
     }
 
-    let (prev_name, prev_time) = prev?;
-    let (next_name, next_time) = next?;
+    let no_term = LunarError::NoSurroundingTerm { time: target_time };
+    let (prev_name, prev_time) = prev.ok_or(no_term.clone())?;
+    let (next_name, next_time) = next.ok_or(no_term)?;
 
-    Some(JieQiContext {
+    Ok(JieQiContext {
         prev_name,
         prev_time,
         next_name,
@@ -174,60 +387,133 @@ pub fn get_jieqi_context(target_time: NaiveDateTime) -> Option<JieQiContext> {
     })
 }
 
+/// Returns the surrounding JieQi for a zoned timestamp.
+///
+/// The term times in the result stay in the table's UTC+8 frame.
+pub fn get_jieqi_context_tz<Tz: TimeZone>(dt: DateTime<Tz>) -> Option<JieQiContext> {
+    try_get_jieqi_context_tz(dt).ok()
+}
+
+/// Like [`get_jieqi_context_tz`], but explains why no context is available.
+pub fn try_get_jieqi_context_tz<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+) -> Result<JieQiContext, LunarError> {
+    try_get_jieqi_context(to_table_time(&dt))
+}
+
 /// Calculate the pillars for a given date and time.
+///
+/// The input is read as UTC+8 clock time, the frame of the table.
 pub fn get_pillars(dt: NaiveDateTime) -> Option<BaziPillars> {
-    let year_data = parsed_year_data(dt.year())?;
+    try_get_pillars(dt).ok()
+}
+
+/// Like [`get_pillars`], but explains why the pillars are unavailable.
+pub fn try_get_pillars(dt: NaiveDateTime) -> Result<BaziPillars, LunarError> {
+    pillars_for(dt, dt, ZiHourRule::default())
+}
+
+/// Calculate the pillars for a zoned date and time.
+///
+/// LiChun and Jie boundaries are compared in the table's UTC+8 frame, while
+/// the day and hour pillars follow the civil clock of the input's zone.
+pub fn get_pillars_tz<Tz: TimeZone>(dt: DateTime<Tz>) -> Option<BaziPillars> {
+    try_get_pillars_tz(dt).ok()
+}
+
+/// Like [`get_pillars_tz`], but explains why the pillars are unavailable.
+pub fn try_get_pillars_tz<Tz: TimeZone>(dt: DateTime<Tz>) -> Result<BaziPillars, LunarError> {
+    pillars_for(dt.naive_local(), to_table_time(&dt), ZiHourRule::default())
+}
+
+/// Calculate the pillars using true solar time (真太阳时) for the hour and day pillars.
+///
+/// LiChun and Jie boundaries still use the actual instant in the table's UTC+8 frame.
+pub fn get_pillars_solar<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    correction: SolarTimeCorrection,
+) -> Option<BaziPillars> {
+    try_get_pillars_solar(dt, correction).ok()
+}
+
+/// Like [`get_pillars_solar`], but explains why the pillars are unavailable.
+pub fn try_get_pillars_solar<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    correction: SolarTimeCorrection,
+) -> Result<BaziPillars, LunarError> {
+    let options = BaziOptions {
+        solar_time: Some(correction),
+        ..BaziOptions::default()
+    };
+    try_get_pillars_with(dt, &options)
+}
+
+/// Calculate the pillars for a zoned date and time under the given conventions.
+pub fn get_pillars_with<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    options: &BaziOptions,
+) -> Option<BaziPillars> {
+    try_get_pillars_with(dt, options).ok()
+}
+
+/// Like [`get_pillars_with`], but explains why the pillars are unavailable.
+pub fn try_get_pillars_with<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    options: &BaziOptions,
+) -> Result<BaziPillars, LunarError> {
+    let civil = match options.solar_time {
+        Some(correction) => correction.solar_time(&dt),
+        None => dt.naive_local(),
+    };
+    pillars_for(civil, to_table_time(&dt), options.zi_hour_rule)
+}
+
+/// Computes the pillars from the civil clock time (`dt`) and the same instant
+/// in the table's UTC+8 frame (`boundary_time`).
+fn pillars_for(
+    dt: NaiveDateTime,
+    boundary_time: NaiveDateTime,
+    zi_hour_rule: ZiHourRule,
+) -> Result<BaziPillars, LunarError> {
+    let year = boundary_time.year();
+    let year_data = parsed_year_data(year)?;
 
     // 1. Year pillar based on LiChun boundary.
-    let bazi_year = if dt < year_data.lichun_time {
-        dt.year() - 1
+    let bazi_year = if boundary_time < year_data.lichun_time {
+        year - 1
     } else {
-        dt.year()
+        year
     };
-    let year_gz = format_gz(get_cycle_index(bazi_year));
+    let year_gz = GanZhi::from_index(get_cycle_index(bazi_year));
 
     // 2. Month pillar based on latest "Jie" before the input.
-    let mut current_jie: Option<(&'static str, NaiveDateTime)> = None;
-    for y in [dt.year() - 1, dt.year(), dt.year() + 1] {
-        if let Some(data) = parsed_year_data(y) {
-            for term in &data.jieqi {
-                let name = normalize_jie_name(term.name);
-                if month_index_from_jie(name).is_some() && term.time <= dt {
-                    if current_jie
-                        .as_ref()
-                        .map(|(_, t)| term.time > *t)
-                        .unwrap_or(true)
-                    {
-                        current_jie = Some((name, term.time));
-                    }
-                }
-            }
-        }
-    }
-    let month_idx = month_index_from_jie(current_jie?.0)?;
+    let (current_jie, _) = surrounding_jie(boundary_time)?;
+    let month_idx = current_jie
+        .and_then(|(name, _)| month_index_from_jie(name))
+        .ok_or(LunarError::NoSurroundingTerm {
+            time: boundary_time,
+        })?;
 
-    // Month Stem: (Year Stem index * 2 + Month index + 2) % 10
-    let year_stem_idx = (get_cycle_index(bazi_year) % 10) as i32;
-    let m_stem_idx = (year_stem_idx * 2 + (month_idx as i32) + 2) % 10;
-    let m_branch_idx = (month_idx + 2) % 12; // Yin = 2
-    let month_gz = format!("{}{}", STEMS[m_stem_idx as usize], BRANCHES[m_branch_idx]);
+    let month_gz = month_pillar(year_gz, month_idx);
 
     // 3. Day pillar. Base date 1900-01-01 is 甲戌 (index 10).
-    let base_date = NaiveDate::from_ymd_opt(1900, 1, 1)?;
-    let diff_days = (dt.date() - base_date).num_days();
-    let day_idx = (10 + diff_days).rem_euclid(60) as usize;
-    let day_gz = format_gz(day_idx);
-
-    // 4. Hour pillar.
-    let h_branch_idx = ((dt.hour() + 1) / 2) % 12;
-    let day_stem_idx = (day_idx % 10) as i32;
-    let h_stem_idx = (day_stem_idx * 2 + (h_branch_idx as i32)) % 10;
-    let hour_gz = format!(
-        "{}{}",
-        STEMS[h_stem_idx as usize], BRANCHES[h_branch_idx as usize]
-    );
-
-    Some(BaziPillars {
+    let day_date = match zi_hour_rule {
+        ZiHourRule::DayChangesAt23 if dt.hour() == 23 => dt.date() + chrono::Days::new(1),
+        _ => dt.date(),
+    };
+    let day_gz = day_pillar(day_date);
+
+    // 4. Hour pillar. The 子 hour of 甲/己 days is 甲子, then 丙子, 戊子, 庚子, 壬子.
+    let hour_day_gz = match zi_hour_rule {
+        ZiHourRule::LateZiNextDayStem if dt.hour() == 23 => {
+            day_pillar(dt.date() + chrono::Days::new(1))
+        }
+        _ => day_gz,
+    };
+    let h_branch = EarthlyBranch::from_index(dt.hour().div_ceil(2) as usize);
+    let hour_gz = GanZhi::from_index(12 * (hour_day_gz.stem().index() % 5) + h_branch.index());
+
+    Ok(BaziPillars {
         year: year_gz,
         month: month_gz,
         day: day_gz,
@@ -284,4 +570,191 @@ mod tests {
         assert_eq!(ctx.prev_name, "大雪");
         assert_eq!(pillars.month, "甲子");
     }
+
+    #[test]
+    fn zoned_input_matches_table_frame() {
+        use chrono::FixedOffset;
+
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        let dt = beijing
+            .from_local_datetime(&parse_dt("1985-04-21 08:00:00"))
+            .unwrap();
+        assert_eq!(get_pillars_tz(dt), get_pillars(dt.naive_local()));
+        assert_eq!(
+            get_jieqi_context_tz(dt).map(|ctx| ctx.diff_prev_seconds),
+            get_jieqi_context(dt.naive_local()).map(|ctx| ctx.diff_prev_seconds)
+        );
+    }
+
+    #[test]
+    fn zoned_input_uses_table_frame_for_lichun() {
+        use chrono::FixedOffset;
+
+        // LiChun 2024 is 2024-02-04 16:27 UTC+8, i.e. 03:27 in New York (UTC-5).
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+        let before = new_york
+            .from_local_datetime(&parse_dt("2024-02-04 03:00:00"))
+            .unwrap();
+        let after = new_york
+            .from_local_datetime(&parse_dt("2024-02-04 04:00:00"))
+            .unwrap();
+        assert_eq!(get_pillars_tz(before).unwrap().year, "癸卯");
+        assert_eq!(get_pillars_tz(before).unwrap().month, "乙丑");
+        assert_eq!(get_pillars_tz(after).unwrap().year, "甲辰");
+        assert_eq!(get_pillars_tz(after).unwrap().month, "丙寅");
+        // Day and hour follow New York clock time.
+        assert_eq!(
+            get_pillars_tz(after).unwrap().to_string(),
+            "甲辰年 丙寅月 戊戌日 甲寅时"
+        );
+        assert_eq!(
+            get_jieqi_context_tz(after).unwrap().diff_prev_seconds,
+            get_jieqi_context(parse_dt("2024-02-04 17:00:00"))
+                .unwrap()
+                .diff_prev_seconds
+        );
+    }
+
+    #[test]
+    fn solar_time_moves_hour_and_day_pillars() {
+        use chrono::FixedOffset;
+
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        // Beijing (116.4°E) in mid February: -14.4 min longitude, about -14 min EoT.
+        let dt = beijing
+            .from_local_datetime(&parse_dt("2024-02-11 13:20:00"))
+            .unwrap();
+        let clock = get_pillars_tz(dt).unwrap();
+        let solar = get_pillars_solar(dt, SolarTimeCorrection::new(116.4).unwrap()).unwrap();
+        assert_eq!(clock.hour.branch(), EarthlyBranch::Wei);
+        assert_eq!(solar.hour.branch(), EarthlyBranch::Wu);
+        assert_eq!(
+            (clock.year, clock.month, clock.day),
+            (solar.year, solar.month, solar.day)
+        );
+
+        // Urumqi (87.6°E) at 01:00 Beijing time is still the previous solar day.
+        let dt = beijing
+            .from_local_datetime(&parse_dt("2024-02-11 01:00:00"))
+            .unwrap();
+        let solar = get_pillars_solar(dt, SolarTimeCorrection::new(87.6).unwrap()).unwrap();
+        assert_eq!(
+            solar.day,
+            get_pillars(parse_dt("2024-02-10 12:00:00")).unwrap().day
+        );
+        assert_eq!(solar.hour.branch(), EarthlyBranch::Hai);
+    }
+
+    #[test]
+    fn regression_zi_hour_rules() {
+        use chrono::FixedOffset;
+
+        let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+        let midnight = BaziOptions::default();
+        let at_23 = BaziOptions {
+            zi_hour_rule: ZiHourRule::DayChangesAt23,
+            ..BaziOptions::default()
+        };
+        let late_zi = BaziOptions {
+            zi_hour_rule: ZiHourRule::LateZiNextDayStem,
+            ..BaziOptions::default()
+        };
+        // 2024-01-28 is 辛卯, 2024-01-29 is 壬辰.
+        let cases = [
+            (
+                "2024-01-28 22:59:59",
+                "辛卯日 己亥时",
+                "辛卯日 己亥时",
+                "辛卯日 己亥时",
+            ),
+            (
+                "2024-01-28 23:00:00",
+                "辛卯日 戊子时",
+                "辛卯日 庚子时",
+                "壬辰日 庚子时",
+            ),
+            (
+                "2024-01-28 23:59:59",
+                "辛卯日 戊子时",
+                "辛卯日 庚子时",
+                "壬辰日 庚子时",
+            ),
+            (
+                "2024-01-29 00:00:00",
+                "壬辰日 庚子时",
+                "壬辰日 庚子时",
+                "壬辰日 庚子时",
+            ),
+            (
+                "2024-01-29 00:59:59",
+                "壬辰日 庚子时",
+                "壬辰日 庚子时",
+                "壬辰日 庚子时",
+            ),
+            (
+                "2024-01-29 01:00:00",
+                "壬辰日 辛丑时",
+                "壬辰日 辛丑时",
+                "壬辰日 辛丑时",
+            ),
+        ];
+        for (input, expected_midnight, expected_late_zi, expected_23) in cases {
+            let dt = beijing.from_local_datetime(&parse_dt(input)).unwrap();
+            let day_hour = |p: BaziPillars| format!("{}日 {}时", p.day, p.hour);
+            let got = get_pillars_with(dt, &midnight).unwrap();
+            assert_eq!(day_hour(got), expected_midnight, "midnight rule, {input}");
+            assert_eq!(got, get_pillars(parse_dt(input)).unwrap());
+            let got = get_pillars_with(dt, &late_zi).unwrap();
+            assert_eq!(day_hour(got), expected_late_zi, "晚子时 rule, {input}");
+            let got = get_pillars_with(dt, &at_23).unwrap();
+            assert_eq!(day_hour(got), expected_23, "23:00 rule, {input}");
+        }
+    }
+
+    #[test]
+    fn pillars_parse_from_text() {
+        let pillars: BaziPillars = "乙丑 庚辰 庚寅 庚辰".parse().unwrap();
+        assert_eq!(
+            pillars,
+            get_pillars(parse_dt("1985-04-21 08:00:00")).unwrap()
+        );
+        assert_eq!(pillars.to_string().parse::<BaziPillars>(), Ok(pillars));
+        assert!("乙丑 庚辰 庚寅".parse::<BaziPillars>().is_err());
+        assert!("乙丑 庚辰 庚寅 庚丑".parse::<BaziPillars>().is_err());
+    }
+
+    #[test]
+    fn errors_explain_unavailable_input() {
+        assert_eq!(
+            try_get_pillars(parse_dt("2100-03-01 00:00:00")),
+            Err(LunarError::OutOfRange {
+                year: 2100,
+                min: 1900,
+                max: 2099
+            })
+        );
+        assert_eq!(
+            try_get_jieqi_context(parse_dt("1800-06-01 00:00:00")).map(|ctx| ctx.next_name),
+            Err(LunarError::OutOfRange {
+                year: 1800,
+                min: 1900,
+                max: 2099
+            })
+        );
+        assert!(matches!(
+            try_get_jieqi_context(parse_dt("1899-12-31 23:59:59")),
+            Err(LunarError::OutOfRange { year: 1899, .. })
+        ));
+        assert!(matches!(
+            surrounding_jie(parse_dt("1800-06-01 00:00:00")),
+            Err(LunarError::OutOfRange { year: 1800, .. })
+        ));
+        // In range, but the next term lies in the missing 2100 row.
+        let edge = parse_dt("2099-12-31 00:00:00");
+        assert_eq!(
+            try_get_jieqi_context(edge).map(|ctx| ctx.next_name),
+            Err(LunarError::NoSurroundingTerm { time: edge })
+        );
+        assert!(get_jieqi_context(parse_dt("1900-01-01 00:00:00")).is_some());
+    }
 }
@@ -0,0 +1,243 @@
+use chrono::{FixedOffset, NaiveDate, Utc};
+use lunar_calendar::{
+    Activity, DateConstraint, DateSearch, DayOfficer, FestivalKind, GanZhi, MAX_SUPPORTED_YEAR,
+    MIN_SUPPORTED_YEAR, TABLE_UTC_OFFSET_SECONDS, try_almanac, try_festivals_in, try_sanfu,
+    try_search_dates, try_shujiu,
+};
+use std::env;
+
+const USAGE: &str = "Usage: almanac [YYYY-MM-DD] [--days N] | almanac FROM --to TO [--officers 成,开] [--no-clash YEAR|干支] [--avoid 月破,四绝,四离] [--good 嫁娶,...] [--prefer 嫁娶,...] [--top N] | almanac --festivals YEAR";
+
+fn parse_input_date(input: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}'. Use YYYY-MM-DD.", input))
+}
+
+fn parse_count(flag: &str, value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(format!(
+            "Invalid {flag} '{}'. Use a positive number.",
+            value
+        )),
+    }
+}
+
+fn parse_list<T>(
+    value: &str,
+    what: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, String> {
+    value
+        .split([',', '，'])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|name| parse(name).ok_or_else(|| format!("Unknown {what} '{}'.", name)))
+        .collect()
+}
+
+/// `--no-clash` takes a birth year of the table (`1985`) or its pillar (`乙丑`).
+fn parse_no_clash(value: &str) -> Result<DateConstraint, String> {
+    if let Ok(year) = value.parse::<i64>() {
+        return i32::try_from(year)
+            .ok()
+            .filter(|year| (MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(year))
+            .map(DateConstraint::no_clash_with_birth_year)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid --no-clash year {}. Use a birth year from {} to {}.",
+                    year, MIN_SUPPORTED_YEAR, MAX_SUPPORTED_YEAR
+                )
+            });
+    }
+    value
+        .parse::<GanZhi>()
+        .map(DateConstraint::NoClashWith)
+        .map_err(|_| {
+            format!(
+                "Invalid --no-clash '{}'. Use a year such as 1985 or a pillar such as 乙丑.",
+                value
+            )
+        })
+}
+
+fn parse_avoid(value: &str) -> Result<Vec<DateConstraint>, String> {
+    parse_list(
+        value,
+        "day to avoid (use 月破, 四绝, 四离)",
+        |name| match name {
+            "月破" => Some(DateConstraint::NotMonthBreak),
+            "四绝" => Some(DateConstraint::NotSiJue),
+            "四离" => Some(DateConstraint::NotSiLi),
+            _ => None,
+        },
+    )
+}
+
+/// Returns the value of `--name VALUE` or `--name=VALUE`, taking it from `args` when separate.
+fn flag_value(
+    arg: &str,
+    name: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Option<Result<String, String>> {
+    if arg == name {
+        Some(
+            args.next()
+                .ok_or_else(|| format!("{name} requires a value. {USAGE}")),
+        )
+    } else {
+        arg.strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('='))
+            .map(|value| Ok(value.to_string()))
+    }
+}
+
+fn print_festivals(year: i32) -> Result<(), Failure> {
+    let days = try_festivals_in(year)
+        .map_err(|e| Failure::Unavailable(format!("Festivals unavailable for {}: {}", year, e)))?;
+    println!("Festivals {}:", year);
+    for day in days {
+        let kind = match day.festival.kind() {
+            FestivalKind::Festival => "",
+            FestivalKind::Memorial => " (memorial)",
+            FestivalKind::Period => " (period)",
+        };
+        println!("  {}{}", day, kind);
+    }
+    if let Ok(fu) = try_sanfu(year) {
+        println!("三伏: {}", fu);
+    }
+    match try_shujiu(year) {
+        Ok(winter) => println!("数九 (冬至 {}): {}", year, winter),
+        Err(e) => println!("数九 (冬至 {}): unavailable ({})", year, e),
+    }
+    Ok(())
+}
+
+/// Why `run` stopped: bad arguments exit with 2, data outside the table with 1.
+enum Failure {
+    Usage(String),
+    Unavailable(String),
+}
+
+impl From<String> for Failure {
+    fn from(msg: String) -> Self {
+        Failure::Usage(msg)
+    }
+}
+
+fn run() -> Result<(), Failure> {
+    let mut input: Option<String> = None;
+    let mut days: Option<usize> = None;
+    let mut to: Option<NaiveDate> = None;
+    let mut top: Option<usize> = None;
+    let mut festivals_year: Option<i32> = None;
+    let mut search = DateSearch::default();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if let Some(value) = flag_value(&arg, "--festivals", &mut args) {
+            let value = value?;
+            let year = value.parse::<i32>().map_err(|_| {
+                format!(
+                    "Invalid festival year '{}'. Use a year such as 2024.",
+                    value
+                )
+            })?;
+            festivals_year = Some(year);
+        } else if let Some(value) = flag_value(&arg, "--days", &mut args) {
+            days = Some(parse_count("day count", &value?)?);
+        } else if let Some(value) = flag_value(&arg, "--to", &mut args) {
+            to = Some(parse_input_date(&value?)?);
+        } else if let Some(value) = flag_value(&arg, "--top", &mut args) {
+            top = Some(parse_count("--top", &value?)?);
+        } else if let Some(value) = flag_value(&arg, "--officers", &mut args) {
+            let officers = parse_list(&value?, "day officer (建除)", DayOfficer::from_name)?;
+            search.constraints.push(DateConstraint::OfficerIn(officers));
+        } else if let Some(value) = flag_value(&arg, "--no-clash", &mut args) {
+            search.constraints.push(parse_no_clash(&value?)?);
+        } else if let Some(value) = flag_value(&arg, "--avoid", &mut args) {
+            search.constraints.extend(parse_avoid(&value?)?);
+        } else if let Some(value) = flag_value(&arg, "--good", &mut args) {
+            let good = parse_list(&value?, "activity", Activity::from_name)?;
+            search
+                .constraints
+                .extend(good.into_iter().map(DateConstraint::Good));
+        } else if let Some(value) = flag_value(&arg, "--prefer", &mut args) {
+            search
+                .prefer
+                .extend(parse_list(&value?, "activity", Activity::from_name)?);
+        } else if input.is_none() {
+            input = Some(arg);
+        } else {
+            return Err(format!("Too many arguments. {USAGE}").into());
+        }
+    }
+
+    if let Some(year) = festivals_year {
+        if input.is_some() || to.is_some() || days.is_some() {
+            return Err(format!("--festivals takes a year instead of a date. {USAGE}").into());
+        }
+        print_festivals(year)?;
+        return Ok(());
+    }
+
+    // Without a date, use today in the table's UTC+8 frame.
+    let start = match input.as_deref() {
+        Some(value) => parse_input_date(value)?,
+        None => {
+            let table_zone =
+                FixedOffset::east_opt(TABLE_UTC_OFFSET_SECONDS).expect("valid UTC+8 offset");
+            Utc::now().with_timezone(&table_zone).date_naive()
+        }
+    };
+
+    let Some(end) = to else {
+        if search != DateSearch::default() || top.is_some() {
+            return Err(format!("Search options need --to. {USAGE}").into());
+        }
+        for (i, date) in start.iter_days().take(days.unwrap_or(1)).enumerate() {
+            if i > 0 {
+                println!();
+            }
+            let day = try_almanac(date).map_err(|e| {
+                Failure::Unavailable(format!("Almanac unavailable for {}: {}", date, e))
+            })?;
+            println!("{}", day);
+        }
+        return Ok(());
+    };
+
+    if days.is_some() {
+        return Err(format!("--days cannot be combined with --to. {USAGE}").into());
+    }
+    if end < start {
+        return Err(format!("--to {} is before {}.", end, start).into());
+    }
+    let candidates = try_search_dates(start..=end, &search).map_err(|e| {
+        Failure::Unavailable(format!("Search unavailable for {}..={}: {}", start, end, e))
+    })?;
+    println!(
+        "Candidates {}..={}: {} day(s)",
+        start,
+        end,
+        candidates.len()
+    );
+    for candidate in candidates.iter().take(top.unwrap_or(usize::MAX)) {
+        println!("  {}", candidate);
+    }
+    Ok(())
+}
+
+fn main() {
+    match run() {
+        Ok(()) => {}
+        Err(Failure::Usage(msg)) => {
+            eprintln!("{msg}");
+            std::process::exit(2);
+        }
+        Err(Failure::Unavailable(msg)) => {
+            eprintln!("{msg}");
+            std::process::exit(1);
+        }
+    }
+}
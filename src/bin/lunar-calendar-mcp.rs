@@ -0,0 +1,341 @@
+use lunar_calendar::{
+    GanZhi, MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR, RpcError, SolarTimeCorrection, ZiHourRule,
+    get_cycle_index, lunar_month_days, rpc_call, serve_with, try_from_lunar_date,
+};
+use serde_json::{Map, Value, json};
+use std::io;
+
+/// MCP revisions this server speaks, newest first.
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// What a tool argument holds; decides its JSON Schema and its check.
+#[derive(Debug, Clone, Copy)]
+enum ArgKind {
+    /// `YYYY-MM-DD[ HH:MM[:SS]]` in UTC+8, or RFC 3339 with an offset.
+    DateTime,
+    Date,
+    /// A Gregorian year of the table.
+    Year,
+    /// A lunar year of the table (numbered by its 正月初一).
+    LunarYear,
+    LunarMonth,
+    LunarDay,
+    IsLeap,
+    ZiRule,
+    Longitude,
+}
+
+impl ArgKind {
+    fn schema(self) -> Value {
+        match self {
+            ArgKind::DateTime => json!({
+                "type": "string",
+                "description": "YYYY-MM-DD[ HH:MM[:SS]] read as UTC+8 (Beijing), or RFC 3339 with an offset such as 1985-04-20T19:00:00-05:00",
+            }),
+            ArgKind::Date => json!({ "type": "string", "format": "date" }),
+            ArgKind::Year | ArgKind::LunarYear => json!({
+                "type": "integer",
+                "minimum": MIN_SUPPORTED_YEAR,
+                "maximum": MAX_SUPPORTED_YEAR,
+            }),
+            ArgKind::LunarMonth => json!({ "type": "integer", "minimum": 1, "maximum": 12 }),
+            ArgKind::LunarDay => json!({ "type": "integer", "minimum": 1, "maximum": 30 }),
+            ArgKind::IsLeap => json!({ "type": "boolean", "default": false }),
+            ArgKind::ZiRule => json!({
+                "type": "string",
+                "enum": ZiHourRule::ALL.map(ZiHourRule::as_str),
+                "default": ZiHourRule::default().as_str(),
+                "description": ZiHourRule::ALL
+                    .map(|rule| format!("{}: {}", rule.as_str(), rule.description()))
+                    .join("; "),
+            }),
+            ArgKind::Longitude => json!({
+                "type": "number",
+                "minimum": -180,
+                "maximum": 180,
+                "description": "Birth longitude in degrees, east positive; day and hour pillars then use true solar time",
+            }),
+        }
+    }
+
+    fn check(self, value: &Value) -> bool {
+        let in_range =
+            |min: i64, max: i64| value.as_i64().is_some_and(|n| (min..=max).contains(&n));
+        match self {
+            ArgKind::DateTime | ArgKind::Date => value.is_string(),
+            ArgKind::ZiRule => value
+                .as_str()
+                .is_some_and(|rule| ZiHourRule::from_name(rule).is_some()),
+            ArgKind::Year | ArgKind::LunarYear => {
+                in_range(MIN_SUPPORTED_YEAR.into(), MAX_SUPPORTED_YEAR.into())
+            }
+            ArgKind::LunarMonth => in_range(1, 12),
+            ArgKind::LunarDay => in_range(1, 30),
+            ArgKind::IsLeap => value.is_boolean(),
+            ArgKind::Longitude => value
+                .as_f64()
+                .is_some_and(|x| SolarTimeCorrection::new(x).is_some()),
+        }
+    }
+}
+
+struct ToolArg {
+    name: &'static str,
+    kind: ArgKind,
+    required: bool,
+    description: &'static str,
+}
+
+struct Tool {
+    name: &'static str,
+    description: &'static str,
+    args: &'static [ToolArg],
+    run: fn(&Map<String, Value>) -> Result<Value, RpcError>,
+}
+
+const DATETIME_ARG: ToolArg = ToolArg {
+    name: "datetime",
+    kind: ArgKind::DateTime,
+    required: true,
+    description: "Moment to look up",
+};
+
+/// Tool table; `tools/list` and argument checks are both generated from it.
+const TOOLS: &[Tool] = &[
+    Tool {
+        name: "get_bazi_pillars",
+        description: "Year, month, day and hour pillars (四柱八字) of a moment, each with stem and branch split out (element, yin/yang, zodiac). Year and month switch at the exact LiChun/Jie instant.",
+        args: &[
+            DATETIME_ARG,
+            ToolArg {
+                name: "zi_rule",
+                kind: ArgKind::ZiRule,
+                required: false,
+                description: "Day-boundary convention for the 子 hour",
+            },
+            ToolArg {
+                name: "longitude",
+                kind: ArgKind::Longitude,
+                required: false,
+                description: "Apply true solar time (真太阳时) for this longitude",
+            },
+        ],
+        run: |args| rpc_call("pillars", &Value::Object(args.clone())),
+    },
+    Tool {
+        name: "get_jieqi_context",
+        description: "Previous and next solar term (节气) around a moment, with their UTC+8 times and the seconds elapsed since / remaining until each.",
+        args: &[DATETIME_ARG],
+        run: |args| rpc_call("jieqi_context", &Value::Object(args.clone())),
+    },
+    Tool {
+        name: "lunar_to_gregorian",
+        description: "Gregorian date of a Chinese lunar date (农历), e.g. lunar 2023 闰二月初一 -> 2023-03-22.",
+        args: &[
+            ToolArg {
+                name: "year",
+                kind: ArgKind::LunarYear,
+                required: true,
+                description: "Lunar year, numbered by the Gregorian year of its 正月初一",
+            },
+            ToolArg {
+                name: "month",
+                kind: ArgKind::LunarMonth,
+                required: true,
+                description: "Lunar month (1 = 正月, 12 = 腊月)",
+            },
+            ToolArg {
+                name: "day",
+                kind: ArgKind::LunarDay,
+                required: true,
+                description: "Day of the lunar month",
+            },
+            ToolArg {
+                name: "is_leap",
+                kind: ArgKind::IsLeap,
+                required: false,
+                description: "The month is the leap month (闰月)",
+            },
+        ],
+        run: lunar_to_gregorian,
+    },
+    Tool {
+        name: "gregorian_to_lunar",
+        description: "Chinese lunar date (农历) of a Gregorian date.",
+        args: &[ToolArg {
+            name: "date",
+            kind: ArgKind::Date,
+            required: true,
+            description: "Gregorian date, YYYY-MM-DD",
+        }],
+        run: |args| rpc_call("lunar_date", &Value::Object(args.clone())),
+    },
+    Tool {
+        name: "get_year_info",
+        description: "Table row of a Gregorian year: lunar new year, LiChun time, leap month, month starts and all 24 solar terms, plus the year's pillar, NaYin and zodiac (in force from LiChun).",
+        args: &[ToolArg {
+            name: "year",
+            kind: ArgKind::Year,
+            required: true,
+            description: "Gregorian year",
+        }],
+        run: year_info,
+    },
+];
+
+fn lunar_to_gregorian(args: &Map<String, Value>) -> Result<Value, RpcError> {
+    let number = |name: &str| args[name].as_i64().unwrap_or_default();
+    let (year, month, day) = (
+        number("year") as i32,
+        number("month") as u8,
+        number("day") as u8,
+    );
+    let is_leap = args
+        .get("is_leap")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let date = try_from_lunar_date(year, month, day, is_leap)?;
+    Ok(json!({
+        "date": date.format("%Y-%m-%d").to_string(),
+        "weekday": date.format("%A").to_string(),
+        "month_days": lunar_month_days(year, month, is_leap),
+    }))
+}
+
+fn year_info(args: &Map<String, Value>) -> Result<Value, RpcError> {
+    let mut info = rpc_call("year_data", &Value::Object(args.clone()))?;
+    let year = info["year"].as_i64().unwrap_or_default() as i32;
+    let pillar = GanZhi::from_index(get_cycle_index(year));
+    info["pillar"] = json!({
+        "ganzhi": pillar.to_string(),
+        "nayin": pillar.nayin(),
+        "zodiac": pillar.branch().zodiac(),
+    });
+    Ok(info)
+}
+
+fn input_schema(tool: &Tool) -> Value {
+    let properties: Map<String, Value> = tool
+        .args
+        .iter()
+        .map(|arg| {
+            let mut schema = arg.kind.schema();
+            let description = match schema.get("description").and_then(Value::as_str) {
+                Some(detail) => format!("{}. {}", arg.description, detail),
+                None => arg.description.to_string(),
+            };
+            schema["description"] = description.into();
+            (arg.name.to_string(), schema)
+        })
+        .collect();
+    let required: Vec<&str> = tool
+        .args
+        .iter()
+        .filter(|arg| arg.required)
+        .map(|arg| arg.name)
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Checks `arguments` against the tool's argument table.
+fn check_arguments(tool: &Tool, arguments: &Value) -> Result<Map<String, Value>, String> {
+    let args = match arguments {
+        Value::Object(map) => map.clone(),
+        Value::Null => Map::new(),
+        _ => return Err("arguments must be an object".to_string()),
+    };
+    for name in args.keys() {
+        if !tool.args.iter().any(|arg| arg.name == name) {
+            return Err(format!("unknown argument `{name}`"));
+        }
+    }
+    for arg in tool.args {
+        match args.get(arg.name) {
+            None | Some(Value::Null) if arg.required => {
+                return Err(format!("missing argument `{}`", arg.name));
+            }
+            None | Some(Value::Null) => {}
+            Some(value) if !arg.kind.check(value) => {
+                return Err(format!(
+                    "argument `{}` does not match its schema {}",
+                    arg.name,
+                    arg.kind.schema()
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(args)
+}
+
+/// A `tools/call` result; failures are reported to the model with `isError`.
+fn call_tool(tool: &Tool, arguments: &Value) -> Value {
+    let outcome =
+        check_arguments(tool, arguments).and_then(|args| (tool.run)(&args).map_err(|e| e.message));
+    match outcome {
+        Ok(value) => json!({
+            "content": [{ "type": "text", "text": value.to_string() }],
+            "structuredContent": value,
+            "isError": false,
+        }),
+        Err(message) => json!({
+            "content": [{ "type": "text", "text": message }],
+            "isError": true,
+        }),
+    }
+}
+
+fn handle(method: &str, params: &Value) -> Result<Value, RpcError> {
+    let error = |code, message: String| RpcError { code, message };
+    match method {
+        "initialize" => {
+            let requested = params["protocolVersion"].as_str();
+            let version = PROTOCOL_VERSIONS
+                .into_iter()
+                .find(|v| Some(*v) == requested)
+                .unwrap_or(PROTOCOL_VERSIONS[0]);
+            Ok(json!({
+                "protocolVersion": version,
+                "capabilities": { "tools": { "listChanged": false } },
+                "serverInfo": { "name": "lunar-calendar", "version": env!("CARGO_PKG_VERSION") },
+                "instructions": "Offline Chinese calendar for 1900-2099: BaZi pillars, solar terms (节气), lunar dates and year data. Times are UTC+8 unless given with an offset.",
+            }))
+        }
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({
+            "tools": TOOLS
+                .iter()
+                .map(|tool| json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "inputSchema": input_schema(tool),
+                }))
+                .collect::<Vec<_>>(),
+        })),
+        "tools/call" => {
+            let name = params["name"].as_str().unwrap_or_default();
+            let tool = TOOLS
+                .iter()
+                .find(|tool| tool.name == name)
+                .ok_or_else(|| error(RpcError::INVALID_PARAMS, format!("unknown tool '{name}'")))?;
+            Ok(call_tool(tool, &params["arguments"]))
+        }
+        _ => Err(error(
+            RpcError::METHOD_NOT_FOUND,
+            format!("unknown method '{method}'"),
+        )),
+    }
+}
+
+/// MCP server over stdio: newline-delimited JSON-RPC 2.0 on stdin/stdout.
+fn main() {
+    if let Err(e) = serve_with(io::stdin().lock(), io::stdout().lock(), handle) {
+        eprintln!("lunar-calendar-mcp: {e}");
+        std::process::exit(1);
+    }
+}
@@ -1,8 +1,26 @@
-use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+};
+use chrono_tz::Tz;
+use lunar_calendar::data::BaziYearData;
 use lunar_calendar::{
-    MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR, get_jieqi_context, get_pillars, get_year_data,
+    BaziOptions, BaziPillars, Gender, JieQiContext, LifeStageSchool, MAX_SUPPORTED_YEAR,
+    MIN_SUPPORTED_YEAR, SolarTimeCorrection, TABLE_UTC_OFFSET_SECONDS, ZiHourRule,
+    chart_life_stages, chart_voids, element_strength, get_year_data, hidden_stems, interactions,
+    jieqi_context_json, lunar_date_json, pillars_json, shen_sha, table_rfc3339, ten_gods,
+    to_table_time, try_flow_months, try_flow_year, try_from_lunar_date, try_get_jieqi_context_tz,
+    try_get_pillars_with, try_luck_pillars, try_to_lunar_date, year_data_json,
 };
+use serde_json::{Value, json};
 use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+
+const USAGE: &str = "Usage: query-lunisolar [YYYY-MM-DD|YYYY-MM-DD HH:MM[:SS]] [--lunar [--leap]] [--tz ZONE] [--longitude DEG] [--zi-rule midnight|late-zi|23] [--gender male|female] [--stage-school reversed|same] [--verify-with-crate] [--format text|json] | --batch [FILE] [--format json|csv] | --flow YEAR";
+
+/// Version of the `--format json` document, see `schema/query-lunisolar.schema.json`.
+/// Bumped whenever a field is removed, renamed or changes meaning.
+const JSON_SCHEMA_VERSION: u32 = 1;
 
 fn parse_input_datetime(input: &str) -> Result<NaiveDateTime, String> {
     if let Ok(dt) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S") {
@@ -11,10 +29,10 @@ fn parse_input_datetime(input: &str) -> Result<NaiveDateTime, String> {
     if let Ok(dt) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
         return Ok(dt);
     }
-    if let Ok(d) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
-        if let Some(dt) = d.and_hms_opt(0, 0, 0) {
-            return Ok(dt);
-        }
+    if let Ok(d) = NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        && let Some(dt) = d.and_hms_opt(0, 0, 0)
+    {
+        return Ok(dt);
     }
     Err(format!(
         "Invalid input '{}'. Use YYYY-MM-DD or YYYY-MM-DD HH:MM[:SS].",
@@ -22,42 +40,619 @@ fn parse_input_datetime(input: &str) -> Result<NaiveDateTime, String> {
     ))
 }
 
+/// Parses a lunar `YYYY-MM-DD [HH:MM[:SS]]` input and converts it to a Gregorian datetime.
+fn parse_lunar_input(input: &str, is_leap: bool) -> Result<NaiveDateTime, String> {
+    let invalid = || {
+        format!(
+            "Invalid lunar input '{}'. Use YYYY-MM-DD or YYYY-MM-DD HH:MM[:SS] (lunar month/day).",
+            input
+        )
+    };
+    let (date_part, time_part) = match input.split_once(' ') {
+        Some((d, t)) => (d, Some(t.trim())),
+        None => (input, None),
+    };
+
+    let mut fields = date_part.split('-');
+    let (Some(y), Some(m), Some(d), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(invalid());
+    };
+    let year: i32 = y.parse().map_err(|_| invalid())?;
+    let month: u8 = m.parse().map_err(|_| invalid())?;
+    let day: u8 = d.parse().map_err(|_| invalid())?;
+
+    let time = match time_part {
+        None => NaiveTime::MIN,
+        Some(t) => NaiveTime::parse_from_str(t, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(t, "%H:%M"))
+            .map_err(|_| invalid())?,
+    };
+
+    try_from_lunar_date(year, month, day, is_leap)
+        .map(|date| date.and_time(time))
+        .map_err(|e| format!("Invalid lunar date '{}': {}.", input, e))
+}
+
+/// Timezone given with `--tz`: a fixed UTC offset or an IANA zone name.
+enum InputZone {
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+/// Parses `+08:00`, `-0500`, `+8`, `UTC`, `UTC+8`, `GMT-03:30` or an IANA name such as `America/New_York`.
+fn parse_timezone(value: &str) -> Result<InputZone, String> {
+    let invalid = || {
+        format!(
+            "Invalid timezone '{}'. Use a UTC offset like +08:00 / -0500 or an IANA name like Asia/Shanghai.",
+            value
+        )
+    };
+    let offset_part = value
+        .strip_prefix("UTC")
+        .or_else(|| value.strip_prefix("GMT"))
+        .unwrap_or(value);
+    if offset_part.is_empty() || offset_part == "Z" {
+        return Ok(InputZone::Fixed(
+            FixedOffset::east_opt(0).ok_or_else(invalid)?,
+        ));
+    }
+    if let Some(sign) = offset_part.chars().next().and_then(|c| match c {
+        '+' => Some(1),
+        '-' => Some(-1),
+        _ => None,
+    }) {
+        let digits = &offset_part[1..];
+        let (hours, minutes) = match digits.split_once(':') {
+            Some((h, m)) => (h, m),
+            None if digits.len() == 4 => digits.split_at(2),
+            None => (digits, "0"),
+        };
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if hours > 14 || minutes > 59 {
+            return Err(invalid());
+        }
+        let seconds = sign * (hours * 3600 + minutes * 60);
+        return FixedOffset::east_opt(seconds)
+            .map(InputZone::Fixed)
+            .ok_or_else(invalid);
+    }
+    value
+        .parse::<Tz>()
+        .map(InputZone::Named)
+        .map_err(|_| invalid())
+}
+
+/// Attaches the zone to a wall-clock datetime. Ambiguous DST times take the earlier instant.
+fn localize(dt: NaiveDateTime, zone: &InputZone) -> Result<DateTime<FixedOffset>, String> {
+    let zoned = match zone {
+        InputZone::Fixed(offset) => offset.from_local_datetime(&dt).earliest(),
+        InputZone::Named(tz) => tz
+            .from_local_datetime(&dt)
+            .earliest()
+            .map(|d| d.with_timezone(&d.offset().fix())),
+    };
+    zoned.ok_or_else(|| format!("{} does not exist in the given timezone (DST gap).", dt))
+}
+
+/// Hidden stems per pillar, e.g. `丑[己 癸 辛] 辰[戊 乙 癸] ...` (main qi first).
+fn format_hidden_stems(pillars: &BaziPillars) -> String {
+    [pillars.year, pillars.month, pillars.day, pillars.hour]
+        .iter()
+        .map(|pillar| {
+            let stems: Vec<String> = hidden_stems(pillar.branch())
+                .iter()
+                .map(|h| h.stem.to_string())
+                .collect();
+            format!("{}[{}]", pillar.branch(), stems.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `--flow YEAR`: annual pillar from LiChun and the twelve Jie months (UTC+8).
+fn print_flow(year: i32) {
+    match try_flow_year(year) {
+        Ok(flow) => println!("Flow year (流年): {}", flow),
+        Err(e) => {
+            eprintln!("Flow year {} unavailable: {}", year, e);
+            std::process::exit(2);
+        }
+    }
+    match try_flow_months(year) {
+        Ok(months) => {
+            println!("Flow months (流月, Jie start → next Jie, UTC+8):");
+            for month in months {
+                println!("  {}", month);
+            }
+        }
+        Err(e) => println!("Flow months (流月): unavailable ({})", e),
+    }
+}
+
+/// ISO 8601 duration such as `P14DT14H15M4S`.
+fn iso_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let s = seconds.unsigned_abs();
+    let (days, hours, minutes, secs) = (s / 86400, s % 86400 / 3600, s % 3600 / 60, s % 60);
+    let mut out = format!("{sign}P");
+    if days > 0 {
+        out += &format!("{days}D");
+    }
+    if hours > 0 || minutes > 0 || secs > 0 || days == 0 {
+        out.push('T');
+        if hours > 0 {
+            out += &format!("{hours}H");
+        }
+        if minutes > 0 {
+            out += &format!("{minutes}M");
+        }
+        if secs > 0 || (hours == 0 && minutes == 0) {
+            out += &format!("{secs}S");
+        }
+    }
+    out
+}
+
+/// [`year_data_json`] plus the input's distance from the lunar new year.
+fn year_json(row: &BaziYearData, date: NaiveDate) -> Value {
+    let mut year = year_data_json(row);
+    let lunar_new_year = NaiveDate::parse_from_str(row.lunar_new_year, "%Y-%m-%d").ok();
+    year["day_offset_from_lunar_new_year"] =
+        json!(lunar_new_year.map(|lny| (date - lny).num_days()));
+    year
+}
+
+/// [`jieqi_context_json`] plus the distances as ISO 8601 durations.
+fn jieqi_json(ctx: &JieQiContext) -> Value {
+    let mut jieqi = jieqi_context_json(ctx);
+    jieqi["prev"]["elapsed"] = iso_duration(ctx.diff_prev_seconds).into();
+    jieqi["next"]["remaining"] = iso_duration(ctx.diff_next_seconds).into();
+    jieqi
+}
+
+/// Options shared by every input of a run, single or `--batch`.
+struct QuerySettings {
+    lunar_input: bool,
+    leap_month: bool,
+    /// `--tz` as given.
+    tz: Option<String>,
+    zone: InputZone,
+    options: BaziOptions,
+}
+
+/// Parses one input, checks it against the table range and attaches the zone.
+fn resolve_input(
+    input: &str,
+    settings: &QuerySettings,
+) -> Result<(NaiveDateTime, DateTime<FixedOffset>), String> {
+    let dt = if settings.lunar_input {
+        parse_lunar_input(input, settings.leap_month)?
+    } else {
+        parse_input_datetime(input)?
+    };
+    if !(MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&dt.year()) {
+        return Err(format!(
+            "Year out of supported table range ({}..={}): {}",
+            MIN_SUPPORTED_YEAR,
+            MAX_SUPPORTED_YEAR,
+            dt.year()
+        ));
+    }
+    Ok((dt, localize(dt, &settings.zone)?))
+}
+
+/// The `--format json` document. Parts outside the table are `null`.
+fn json_report(input: &str, settings: &QuerySettings, zoned: DateTime<FixedOffset>) -> Value {
+    let dt = zoned.naive_local();
+    let options = &settings.options;
+    let zi_rule = options.zi_hour_rule.as_str();
+    let pillars = try_get_pillars_with(zoned, options)
+        .ok()
+        .map(|p| pillars_json(&p));
+    let lunar_date = try_to_lunar_date(dt.date())
+        .ok()
+        .map(|lunar| lunar_date_json(&lunar));
+    json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "input": {
+            "raw": input,
+            "calendar": if settings.lunar_input { "lunar" } else { "gregorian" },
+            "leap_month": settings.leap_month,
+            "timezone": settings.tz,
+            "utc_offset_seconds": zoned.offset().local_minus_utc(),
+            "datetime": zoned.to_rfc3339(),
+            "table_datetime": table_rfc3339(to_table_time(&zoned)),
+            "zi_rule": zi_rule,
+        },
+        "year": get_year_data(dt.year()).map(|row| year_json(row, dt.date())),
+        "pillars": pillars,
+        "jieqi": try_get_jieqi_context_tz(zoned).ok().map(|ctx| jieqi_json(&ctx)),
+        "lunar_date": lunar_date,
+    })
+}
+
+/// Splits one CSV record. Fields may be double-quoted, with `""` for a quote.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        let field = fields.last_mut().expect("at least one field");
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => field.push(c),
+        }
+    }
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// Quotes a CSV field when it holds a comma, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+const BATCH_CSV_HEADER: &str = "line,id,input,datetime,table_datetime,year_pillar,month_pillar,day_pillar,hour_pillar,lunar_date,prev_jieqi,prev_jieqi_time,next_jieqi,next_jieqi_time,error";
+
+/// One `--batch --format csv` row, flattened from the JSON report.
+fn batch_csv_row(
+    line: usize,
+    id: Option<&str>,
+    input: &str,
+    result: &Result<Value, String>,
+) -> String {
+    let mut fields = vec![
+        line.to_string(),
+        id.unwrap_or("").to_string(),
+        input.to_string(),
+    ];
+    match result {
+        Ok(report) => {
+            let text = |value: &Value| value.as_str().unwrap_or("").to_string();
+            fields.push(text(&report["input"]["datetime"]));
+            fields.push(text(&report["input"]["table_datetime"]));
+            for pillar in ["year", "month", "day", "hour"] {
+                fields.push(text(&report["pillars"][pillar]["ganzhi"]));
+            }
+            fields.push(text(&report["lunar_date"]["text"]));
+            for side in ["prev", "next"] {
+                fields.push(text(&report["jieqi"][side]["name"]));
+                fields.push(text(&report["jieqi"][side]["time"]));
+            }
+            fields.push(String::new());
+        }
+        Err(msg) => {
+            fields.extend(std::iter::repeat_n(String::new(), 11));
+            fields.push(msg.clone());
+        }
+    }
+    fields
+        .iter()
+        .map(|f| csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `--batch`: one result per input line, written as soon as it is computed.
+///
+/// Input is one datetime per line, or CSV whose header row names a
+/// `datetime` column (and optionally `id`). Blank lines and `#` comments are
+/// skipped. A bad line, including one that is not UTF-8, yields an error
+/// record and the batch goes on.
+fn run_batch(
+    source: Option<&str>,
+    csv_output: bool,
+    settings: &QuerySettings,
+) -> Result<(), String> {
+    let mut reader: Box<dyn BufRead> = match source {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => {
+            Box::new(BufReader::new(File::open(path).map_err(|e| {
+                format!("Cannot open batch input '{}': {}", path, e)
+            })?))
+        }
+    };
+    let mut out = io::stdout().lock();
+    if csv_output && writeln!(out, "{BATCH_CSV_HEADER}").is_err() {
+        return Ok(());
+    }
+    // (id column, datetime column) once a CSV header has been seen.
+    let mut columns: Option<(Option<usize>, usize)> = None;
+    let mut first = true;
+    let mut bytes = Vec::new();
+    for line_number in 1.. {
+        bytes.clear();
+        let read = reader
+            .read_until(b'\n', &mut bytes)
+            .map_err(|e| format!("Cannot read batch input: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        let (line, utf8_ok) = match String::from_utf8(bytes.clone()) {
+            Ok(line) => (line, true),
+            Err(_) => (String::from_utf8_lossy(&bytes).into_owned(), false),
+        };
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if std::mem::take(&mut first) {
+            let header = split_csv(trimmed);
+            let position = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+            if let Some(datetime) = position("datetime") {
+                columns = Some((position("id"), datetime));
+                continue;
+            }
+        }
+        let (id, input) = match columns {
+            None => (None, trimmed.to_string()),
+            Some((id_column, datetime_column)) => {
+                let fields = split_csv(trimmed);
+                (
+                    id_column.and_then(|i| fields.get(i).cloned()),
+                    fields.get(datetime_column).cloned().unwrap_or_default(),
+                )
+            }
+        };
+        let result = if utf8_ok {
+            resolve_input(&input, settings).map(|(_, zoned)| json_report(&input, settings, zoned))
+        } else {
+            Err("Input line is not valid UTF-8.".to_string())
+        };
+        let record = if csv_output {
+            batch_csv_row(line_number, id.as_deref(), &input, &result)
+        } else {
+            let mut record = json!({ "line": line_number, "id": id, "input": input });
+            match result {
+                Ok(report) => record["result"] = report,
+                Err(msg) => record["error"] = msg.into(),
+            }
+            record.to_string()
+        };
+        // Stop quietly when the reader of our output goes away.
+        if writeln!(out, "{record}").is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the value of `--name VALUE` or `--name=VALUE`, taking it from `args` when separate.
+fn flag_value(
+    arg: &str,
+    name: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Option<Result<String, String>> {
+    if arg == name {
+        Some(
+            args.next()
+                .ok_or_else(|| format!("{name} requires a value. {USAGE}")),
+        )
+    } else {
+        arg.strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('='))
+            .map(|value| Ok(value.to_string()))
+    }
+}
+
+/// Prints a usage error and exits with status 2.
+fn exit_usage(msg: &str) -> ! {
+    eprintln!("{msg}");
+    std::process::exit(2);
+}
+
 fn main() {
     let mut input: Option<String> = None;
     let mut verify_with_crate = false;
-    for arg in env::args().skip(1) {
+    let mut lunar_input = false;
+    let mut leap_month = false;
+    let mut tz_arg: Option<String> = None;
+    let mut longitude_arg: Option<String> = None;
+    let mut zi_rule_arg: Option<String> = None;
+    let mut gender_arg: Option<String> = None;
+    let mut stage_school_arg: Option<String> = None;
+    let mut flow_arg: Option<String> = None;
+    let mut format_arg: Option<String> = None;
+    let mut batch = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
         if arg == "--verify-with-crate" {
             verify_with_crate = true;
+        } else if arg == "--batch" {
+            batch = true;
+        } else if arg == "--lunar" {
+            lunar_input = true;
+        } else if arg == "--leap" {
+            leap_month = true;
+        } else if let Some(value) = flag_value(&arg, "--tz", &mut args) {
+            tz_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--longitude", &mut args) {
+            longitude_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--zi-rule", &mut args) {
+            zi_rule_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--gender", &mut args) {
+            gender_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--stage-school", &mut args) {
+            stage_school_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--flow", &mut args) {
+            flow_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
+        } else if let Some(value) = flag_value(&arg, "--format", &mut args) {
+            format_arg = Some(value.unwrap_or_else(|msg| exit_usage(&msg)));
         } else if input.is_none() {
             input = Some(arg);
         } else {
+            eprintln!("Too many arguments. {USAGE}");
+            std::process::exit(2);
+        }
+    }
+
+    // `--batch` streams JSON Lines unless asked for CSV.
+    let (json_output, csv_output) = match (format_arg.as_deref(), batch) {
+        (None, false) | (Some("text"), false) => (false, false),
+        (None, true) | (Some("json"), _) => (true, false),
+        (Some("csv"), true) => (false, true),
+        (Some("text"), true) | (Some("csv"), false) => {
+            eprintln!(
+                "--batch takes --format json (JSON Lines) or csv; a single query takes text or json."
+            );
+            std::process::exit(2);
+        }
+        (Some(other), _) => {
+            eprintln!(
+                "Invalid format '{}'. Use 'text', 'json' or (with --batch) 'csv'.",
+                other
+            );
+            std::process::exit(2);
+        }
+    };
+    if (json_output || csv_output)
+        && (flow_arg.is_some()
+            || longitude_arg.is_some()
+            || gender_arg.is_some()
+            || stage_school_arg.is_some()
+            || verify_with_crate)
+    {
+        eprintln!(
+            "--format json/csv and --batch cover input, year, pillars, JieQi and lunar date; they cannot be combined with --flow, --longitude, --gender, --stage-school or --verify-with-crate."
+        );
+        std::process::exit(2);
+    }
+
+    if let Some(value) = flow_arg.as_deref() {
+        if input.is_some() {
+            eprintln!("--flow takes a year instead of a datetime. {USAGE}");
+            std::process::exit(2);
+        }
+        let Ok(year) = value.parse::<i32>() else {
+            eprintln!(
+                "Invalid flow year '{}'. Use a Gregorian year such as 2027.",
+                value
+            );
+            std::process::exit(2);
+        };
+        print_flow(year);
+        return;
+    }
+
+    if leap_month && !lunar_input {
+        eprintln!("--leap is only valid together with --lunar.");
+        std::process::exit(2);
+    }
+
+    let zone = match tz_arg.as_deref().map(parse_timezone) {
+        None => InputZone::Fixed(
+            FixedOffset::east_opt(TABLE_UTC_OFFSET_SECONDS).expect("valid UTC+8 offset"),
+        ),
+        Some(Ok(zone)) => zone,
+        Some(Err(msg)) => {
+            eprintln!("{msg}");
+            std::process::exit(2);
+        }
+    };
+    let solar_correction = match longitude_arg.as_deref() {
+        None => None,
+        Some(value) => match value.parse().ok().and_then(SolarTimeCorrection::new) {
+            Some(correction) => Some(correction),
+            None => {
+                eprintln!(
+                    "Invalid longitude '{}'. Use degrees in -180..=180, east positive (e.g. 116.4).",
+                    value
+                );
+                std::process::exit(2);
+            }
+        },
+    };
+
+    let zi_hour_rule = match zi_rule_arg.as_deref() {
+        None => ZiHourRule::default(),
+        Some(name) => ZiHourRule::from_name(name).unwrap_or_else(|| {
+            let names: Vec<&str> = ZiHourRule::ALL.map(ZiHourRule::as_str).into();
+            eprintln!(
+                "Invalid zi rule '{}'. Use one of {}.",
+                name,
+                names.join(", ")
+            );
+            std::process::exit(2);
+        }),
+    };
+    let gender = match gender_arg.as_deref() {
+        None => None,
+        Some("male" | "m" | "男") => Some(Gender::Male),
+        Some("female" | "f" | "女") => Some(Gender::Female),
+        Some(other) => {
+            eprintln!("Invalid gender '{}'. Use 'male' or 'female'.", other);
+            std::process::exit(2);
+        }
+    };
+    let stage_school = match stage_school_arg.as_deref() {
+        None | Some("reversed") => LifeStageSchool::YinReversed,
+        Some("same") => LifeStageSchool::YinYangSame,
+        Some(other) => {
             eprintln!(
-                "Too many arguments. Usage: query-lunisolar [YYYY-MM-DD|YYYY-MM-DD HH:MM[:SS]] [--verify-with-crate]"
+                "Invalid stage school '{}'. Use 'reversed' (阳顺阴逆) or 'same' (阴阳同生同死).",
+                other
             );
             std::process::exit(2);
         }
+    };
+    let options = BaziOptions {
+        zi_hour_rule,
+        solar_time: None,
+    };
+    let settings = QuerySettings {
+        lunar_input,
+        leap_month,
+        tz: tz_arg.clone(),
+        zone,
+        options,
+    };
+
+    if batch {
+        if let Err(msg) = run_batch(input.as_deref(), csv_output, &settings) {
+            eprintln!("{msg}");
+            std::process::exit(2);
+        }
+        return;
     }
 
     let input = input.unwrap_or_else(|| "2026-02-04 00:00:00".to_string());
-    let dt = match parse_input_datetime(&input) {
-        Ok(d) => d,
+    let (dt, zoned) = match resolve_input(&input, &settings) {
+        Ok(resolved) => resolved,
         Err(msg) => {
             eprintln!("{msg}");
             std::process::exit(2);
         }
     };
 
-    if !(MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&dt.year()) {
-        eprintln!(
-            "Year out of supported table range ({}..={}): {}",
-            MIN_SUPPORTED_YEAR,
-            MAX_SUPPORTED_YEAR,
-            dt.year()
+    if json_output {
+        let report = json_report(&input, &settings, zoned);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).expect("JSON values serialize")
         );
-        std::process::exit(2);
+        return;
     }
 
+    if lunar_input {
+        let leap_label = if leap_month { " (leap month)" } else { "" };
+        println!("Input lunar date: {}{}", input, leap_label);
+    }
     println!("Input datetime: {}", dt);
+    if let Some(name) = tz_arg.as_deref() {
+        println!("Input timezone: {} (UTC{})", name, zoned.offset());
+        println!("Table frame (UTC+8): {}", to_table_time(&zoned));
+    }
     println!("--- Primary path: local table + local algorithm (offline) ---");
 
     if let Some(year_info) = get_year_data(dt.year()) {
@@ -81,16 +676,105 @@ fn main() {
         }
     }
 
-    if let Some(pillars) = get_pillars(dt) {
-        println!("BaZi (local algo): {}", pillars);
-    } else {
-        println!("BaZi (local algo): unavailable for this input");
+    match try_to_lunar_date(dt.date()) {
+        Ok(lunar) => println!("Lunar Date (table): {}", lunar),
+        Err(e) => println!("Lunar Date (table): unavailable ({})", e),
     }
 
-    if let Some(ctx) = get_jieqi_context(dt) {
-        println!("JieQi context (table): {}", ctx);
-    } else {
-        println!("JieQi context (table): unavailable for this input");
+    if zi_rule_arg.is_some() {
+        println!("Zi-hour rule: {}", zi_hour_rule.description());
+    }
+
+    let clock_pillars = try_get_pillars_with(zoned, &options);
+    match &clock_pillars {
+        Ok(pillars) => {
+            println!("BaZi (local algo): {}", pillars);
+            println!("Hidden stems (藏干): {}", format_hidden_stems(pillars));
+        }
+        Err(e) => println!("BaZi (local algo): unavailable ({})", e),
+    }
+
+    if let Some(correction) = solar_correction {
+        let offset = correction.offset_seconds(&zoned);
+        let sign = if offset < 0 { "-" } else { "+" };
+        println!(
+            "True solar time (longitude {}): {} ({}{}m{:02}s vs clock)",
+            correction.longitude(),
+            correction.solar_time(&zoned).format("%Y-%m-%d %H:%M:%S"),
+            sign,
+            offset.abs() / 60,
+            offset.abs() % 60
+        );
+        let solar_options = BaziOptions {
+            solar_time: Some(correction),
+            ..options
+        };
+        match try_get_pillars_with(zoned, &solar_options) {
+            Ok(pillars) => {
+                println!("BaZi (true solar time): {}", pillars);
+                println!(
+                    "Hidden stems (true solar time): {}",
+                    format_hidden_stems(&pillars)
+                );
+            }
+            Err(e) => println!("BaZi (true solar time): unavailable ({})", e),
+        }
+    }
+
+    if let Ok(pillars) = &clock_pillars {
+        let gods = ten_gods(pillars);
+        println!("Ten Gods (十神, day master {}):", gods.day_master);
+        for (label, pillar) in [
+            ("年柱", &gods.year),
+            ("月柱", &gods.month),
+            ("日柱", &gods.day),
+            ("时柱", &gods.hour),
+        ] {
+            println!("  {} {}", label, pillar);
+        }
+        println!("Element strength (五行): {}", element_strength(pillars));
+        let found = interactions(pillars);
+        if found.is_empty() {
+            println!("Interactions (合冲刑害破): none");
+        } else {
+            println!("Interactions (合冲刑害破):");
+            for interaction in found {
+                println!("  {}", interaction);
+            }
+        }
+        let stages = chart_life_stages(pillars, stage_school);
+        println!(
+            "Life stages (十二长生, day master {}): {}",
+            stages.day_master, stages
+        );
+        let [day_void, year_void] = chart_voids(pillars);
+        println!("Xun void (旬空): {} | {}", day_void, year_void);
+        let stars = shen_sha(pillars);
+        if stars.is_empty() {
+            println!("Symbolic stars (神煞): none");
+        } else {
+            println!("Symbolic stars (神煞):");
+            for star in stars {
+                println!("  {}", star);
+            }
+        }
+    }
+
+    if let Some(gender) = gender {
+        match try_luck_pillars(zoned, gender, &options) {
+            Ok(luck) => {
+                println!("Luck pillars (大运):");
+                for pillar in luck {
+                    println!("  {}", pillar);
+                }
+            }
+            Err(e) => println!("Luck pillars (大运): unavailable ({})", e),
+        }
+    }
+
+    match try_get_jieqi_context_tz(zoned) {
+        Ok(ctx) => println!("JieQi context (table): {}", ctx),
+        Err(e) => println!("JieQi context (table): unavailable ({})", e),
     }
 
     if verify_with_crate {
@@ -52,7 +52,7 @@ fn main() {
     match LunisolarDate::from_solar_date(solar_date) {
         Ok(lunar_date) => {
             println!("Solar Date: {}", solar_date);
-            println!("Lunar Date: {}", lunar_date.to_string());
+            println!("Lunar Date: {}", lunar_date);
         }
         Err(e) => println!("Error: {:?}", e),
     }
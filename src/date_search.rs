@@ -0,0 +1,242 @@
+use crate::almanac::{Activity, AlmanacDay, DayOfficer, try_almanac};
+use crate::error::LunarError;
+use crate::ganzhi::GanZhi;
+use crate::table_lookup::get_cycle_index;
+use chrono::NaiveDate;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A condition every candidate day of a [`DateSearch`] must meet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateConstraint {
+    /// The day branch does not clash (冲) the branch of this pillar, e.g. a
+    /// person's birth-year pillar.
+    NoClashWith(GanZhi),
+    /// The day officer (建除) is one of these.
+    OfficerIn(Vec<DayOfficer>),
+    /// Not a 月破 day (day branch clashes the month branch, the 破 officer).
+    NotMonthBreak,
+    /// Not a 四绝日.
+    NotSiJue,
+    /// Not a 四离日.
+    NotSiLi,
+    /// The activity is listed under 宜.
+    Good(Activity),
+    /// The activity is not listed under 忌.
+    NotBad(Activity),
+}
+
+impl DateConstraint {
+    /// [`DateConstraint::NoClashWith`] the year pillar of a Gregorian birth year.
+    ///
+    /// The year is taken as is; births before LiChun belong to the previous year.
+    pub fn no_clash_with_birth_year(year: i32) -> DateConstraint {
+        DateConstraint::NoClashWith(GanZhi::from_index(get_cycle_index(year)))
+    }
+
+    /// Whether the almanac day meets this constraint.
+    pub fn matches(&self, day: &AlmanacDay) -> bool {
+        match self {
+            DateConstraint::NoClashWith(pillar) => day.clash.pillar.branch() != pillar.branch(),
+            DateConstraint::OfficerIn(officers) => officers.contains(&day.officer),
+            DateConstraint::NotMonthBreak => day.officer != DayOfficer::Po,
+            DateConstraint::NotSiJue => !day.si_jue,
+            DateConstraint::NotSiLi => !day.si_li,
+            DateConstraint::Good(activity) => day.good.contains(activity),
+            DateConstraint::NotBad(activity) => !day.bad.contains(activity),
+        }
+    }
+}
+
+/// Constraints and ranking preferences for [`search_dates`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateSearch {
+    /// Every constraint must hold for a day to be a candidate.
+    pub constraints: Vec<DateConstraint>,
+    /// Activities the day is chosen for; they drive the ranking.
+    pub prefer: Vec<Activity>,
+}
+
+/// Points per preferred activity under 宜 (or lost under 忌).
+pub const PREFERRED_ACTIVITY_SCORE: i32 = 10;
+
+impl DateSearch {
+    /// Ranking score of a day: [`PREFERRED_ACTIVITY_SCORE`] for each preferred
+    /// activity under 宜, minus the same under 忌, plus one per other 宜 entry
+    /// and minus one per other 忌 entry.
+    pub fn score(&self, day: &AlmanacDay) -> i32 {
+        let weight = |activity: &Activity| {
+            if self.prefer.contains(activity) {
+                PREFERRED_ACTIVITY_SCORE
+            } else {
+                1
+            }
+        };
+        day.good.iter().map(weight).sum::<i32>() - day.bad.iter().map(weight).sum::<i32>()
+    }
+
+    /// Whether the almanac day meets every constraint.
+    pub fn matches(&self, day: &AlmanacDay) -> bool {
+        self.constraints.iter().all(|c| c.matches(day))
+    }
+}
+
+/// A day that passed every constraint, with its ranking score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateCandidate {
+    pub day: AlmanacDay,
+    pub score: i32,
+}
+
+impl fmt::Display for DateCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}日 {}日 {} {} (score {})",
+            self.day.date,
+            self.day.day,
+            self.day.officer,
+            self.day.mansion,
+            self.day.clash,
+            self.score
+        )
+    }
+}
+
+/// Days in `dates` meeting every constraint, best score first (earlier date
+/// first on ties). Days outside the table are skipped.
+pub fn search_dates(dates: RangeInclusive<NaiveDate>, search: &DateSearch) -> Vec<DateCandidate> {
+    rank(
+        dates
+            .start()
+            .iter_days()
+            .take_while(|date| date <= dates.end())
+            .filter_map(|date| try_almanac(date).ok()),
+        search,
+    )
+}
+
+/// Like [`search_dates`], but fails on the first day outside the table.
+pub fn try_search_dates(
+    dates: RangeInclusive<NaiveDate>,
+    search: &DateSearch,
+) -> Result<Vec<DateCandidate>, LunarError> {
+    let days = dates
+        .start()
+        .iter_days()
+        .take_while(|date| date <= dates.end())
+        .map(try_almanac)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rank(days.into_iter(), search))
+}
+
+fn rank(days: impl Iterator<Item = AlmanacDay>, search: &DateSearch) -> Vec<DateCandidate> {
+    let mut candidates: Vec<DateCandidate> = days
+        .filter(|day| search.matches(day))
+        .map(|day| DateCandidate {
+            score: search.score(&day),
+            day,
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.score.cmp(&a.score).then(a.day.date.cmp(&b.day.date)));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ganzhi::EarthlyBranch;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn constraints_filter_every_candidate() {
+        let search = DateSearch {
+            constraints: vec![
+                DateConstraint::no_clash_with_birth_year(1985),
+                DateConstraint::OfficerIn(vec![DayOfficer::Cheng, DayOfficer::Kai]),
+                DateConstraint::NotMonthBreak,
+                DateConstraint::NotSiJue,
+            ],
+            prefer: vec![Activity::Marriage],
+        };
+        let found = search_dates(date(2024, 1, 1)..=date(2024, 3, 31), &search);
+        assert!(!found.is_empty());
+        for candidate in &found {
+            let day = &candidate.day;
+            assert!(matches!(day.officer, DayOfficer::Cheng | DayOfficer::Kai));
+            // 1985 is 乙丑: 未 days clash it.
+            assert_ne!(day.day.branch(), EarthlyBranch::Wei);
+            assert!(!day.si_jue);
+        }
+        // 2024-02-03 is 成 but 四绝.
+        assert!(found.iter().all(|c| c.day.date != date(2024, 2, 3)));
+    }
+
+    #[test]
+    fn birth_year_constraint_for_any_year() {
+        assert_eq!(
+            DateConstraint::no_clash_with_birth_year(1985),
+            DateConstraint::NoClashWith("乙丑".parse().unwrap())
+        );
+        // Far outside the table, the year still maps into the cycle.
+        assert_eq!(
+            DateConstraint::no_clash_with_birth_year(i32::MIN),
+            DateConstraint::NoClashWith(GanZhi::from_index(48))
+        );
+    }
+
+    #[test]
+    fn ranked_by_score_then_date() {
+        let search = DateSearch {
+            constraints: vec![DateConstraint::NotBad(Activity::Marriage)],
+            prefer: vec![Activity::Marriage],
+        };
+        let found = search_dates(date(2024, 5, 1)..=date(2024, 5, 31), &search);
+        for pair in found.windows(2) {
+            assert!(
+                pair[0].score > pair[1].score
+                    || (pair[0].score == pair[1].score && pair[0].day.date < pair[1].day.date)
+            );
+        }
+        let best = &found[0];
+        assert!(best.day.good.contains(&Activity::Marriage));
+        assert!(
+            found
+                .iter()
+                .all(|c| !c.day.bad.contains(&Activity::Marriage))
+        );
+    }
+
+    #[test]
+    fn month_break_is_po() {
+        let search = DateSearch {
+            constraints: vec![DateConstraint::NotMonthBreak],
+            ..DateSearch::default()
+        };
+        let all =
+            try_search_dates(date(2024, 3, 1)..=date(2024, 3, 31), &DateSearch::default()).unwrap();
+        let kept = try_search_dates(date(2024, 3, 1)..=date(2024, 3, 31), &search).unwrap();
+        let po = all
+            .iter()
+            .filter(|c| c.day.officer == DayOfficer::Po)
+            .count();
+        assert!(po >= 2);
+        assert_eq!(kept.len(), all.len() - po);
+    }
+
+    #[test]
+    fn table_edges() {
+        let range = date(2099, 12, 30)..=date(2100, 1, 2);
+        assert_eq!(search_dates(range.clone(), &DateSearch::default()).len(), 2);
+        assert!(matches!(
+            try_search_dates(range, &DateSearch::default()),
+            Err(LunarError::OutOfRange { year: 2100, .. })
+        ));
+    }
+}
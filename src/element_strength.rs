@@ -0,0 +1,271 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{EarthlyBranch, Element, HeavenlyStem};
+use crate::hidden_stems::{HiddenStemWeights, weighted_hidden_stems};
+use std::fmt;
+
+/// Seasonal state (旺相休囚死) of an element in the month of the chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeasonalState {
+    /// 旺: the month's own element.
+    Prosperous,
+    /// 相: produced by the month's element.
+    Strong,
+    /// 休: produces the month's element.
+    Resting,
+    /// 囚: overcomes the month's element.
+    Confined,
+    /// 死: overcome by the month's element.
+    Dead,
+}
+
+impl SeasonalState {
+    /// State of `element` in a month whose branch is `month_branch`.
+    ///
+    /// 辰戌丑未 months count as Earth months.
+    pub fn of(element: Element, month_branch: EarthlyBranch) -> SeasonalState {
+        let season = month_branch.element();
+        if element == season {
+            SeasonalState::Prosperous
+        } else if season.generates() == element {
+            SeasonalState::Strong
+        } else if element.generates() == season {
+            SeasonalState::Resting
+        } else if element.overcomes() == season {
+            SeasonalState::Confined
+        } else {
+            SeasonalState::Dead
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SeasonalState::Prosperous => "旺",
+            SeasonalState::Strong => "相",
+            SeasonalState::Resting => "休",
+            SeasonalState::Confined => "囚",
+            SeasonalState::Dead => "死",
+        }
+    }
+}
+
+impl fmt::Display for SeasonalState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Weights used by [`element_strength_with`].
+///
+/// Each visible stem adds `stem` to its element and each branch adds `branch`,
+/// split over its hidden stems by `hidden`. Every element's total is then
+/// multiplied by the factor of its seasonal state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StrengthWeights {
+    pub stem: f64,
+    pub branch: f64,
+    pub hidden: HiddenStemWeights,
+    /// Factors for 旺, 相, 休, 囚, 死 in that order.
+    pub seasonal: [f64; 5],
+}
+
+impl Default for StrengthWeights {
+    /// Stems and branches 1.0 each, hidden stems 6:3:1 (7:3),
+    /// seasonal factors 旺 1.5, 相 1.25, 休 1.0, 囚 0.75, 死 0.5.
+    fn default() -> Self {
+        StrengthWeights {
+            stem: 1.0,
+            branch: 1.0,
+            hidden: HiddenStemWeights::default(),
+            seasonal: [1.5, 1.25, 1.0, 0.75, 0.5],
+        }
+    }
+}
+
+impl StrengthWeights {
+    fn seasonal_factor(&self, state: SeasonalState) -> f64 {
+        self.seasonal[state as usize]
+    }
+}
+
+/// Overall strength of the day master (日主).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DayMasterStrength {
+    /// 身强: support share above 55%.
+    Strong,
+    /// 中和: support share between 45% and 55%.
+    Balanced,
+    /// 身弱: support share below 45%.
+    Weak,
+}
+
+impl DayMasterStrength {
+    fn from_support_share(share: f64) -> DayMasterStrength {
+        if share > 0.55 {
+            DayMasterStrength::Strong
+        } else if share < 0.45 {
+            DayMasterStrength::Weak
+        } else {
+            DayMasterStrength::Balanced
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DayMasterStrength::Strong => "身强",
+            DayMasterStrength::Balanced => "中和",
+            DayMasterStrength::Weak => "身弱",
+        }
+    }
+}
+
+impl fmt::Display for DayMasterStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Five-element scores of a chart and the resulting day-master strength.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementStrength {
+    /// Weighted score per element, indexed like [`Element::ALL`].
+    pub scores: [f64; 5],
+    /// Seasonal state per element, indexed like [`Element::ALL`].
+    pub seasonal: [SeasonalState; 5],
+    pub day_master: HeavenlyStem,
+    /// Share of the total held by the day master's element and the element producing it (比劫 + 印).
+    pub support_share: f64,
+    pub strength: DayMasterStrength,
+}
+
+impl ElementStrength {
+    pub fn score(&self, element: Element) -> f64 {
+        self.scores[element as usize]
+    }
+
+    pub fn seasonal_state(&self, element: Element) -> SeasonalState {
+        self.seasonal[element as usize]
+    }
+}
+
+impl fmt::Display for ElementStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for element in Element::ALL {
+            write!(
+                f,
+                "{}{}({}) ",
+                element,
+                (self.score(element) * 100.0).round() / 100.0,
+                self.seasonal_state(element)
+            )?;
+        }
+        write!(
+            f,
+            "| 日主 {}{} {} ({:.0}% support)",
+            self.day_master,
+            self.day_master.element(),
+            self.strength,
+            self.support_share * 100.0
+        )
+    }
+}
+
+/// Scores the five elements of a chart with the default [`StrengthWeights`].
+pub fn element_strength(pillars: &BaziPillars) -> ElementStrength {
+    element_strength_with(pillars, &StrengthWeights::default())
+}
+
+/// Scores the five elements of a chart with custom weights.
+pub fn element_strength_with(pillars: &BaziPillars, weights: &StrengthWeights) -> ElementStrength {
+    let month_branch = pillars.month.branch();
+    let seasonal = Element::ALL.map(|element| SeasonalState::of(element, month_branch));
+
+    let mut raw = [0.0; 5];
+    for pillar in [pillars.year, pillars.month, pillars.day, pillars.hour] {
+        raw[pillar.stem().element() as usize] += weights.stem;
+        for (hidden, share) in weighted_hidden_stems(pillar.branch(), &weights.hidden) {
+            raw[hidden.stem.element() as usize] += weights.branch * share;
+        }
+    }
+    let scores: [f64; 5] = std::array::from_fn(|i| raw[i] * weights.seasonal_factor(seasonal[i]));
+
+    let day_master = pillars.day.stem();
+    let me = day_master.element();
+    let resource = Element::ALL
+        .into_iter()
+        .find(|e| e.generates() == me)
+        .expect("every element has a producer");
+    let total: f64 = scores.iter().sum();
+    let support_share = if total > 0.0 {
+        (scores[me as usize] + scores[resource as usize]) / total
+    } else {
+        0.0
+    };
+
+    ElementStrength {
+        scores,
+        seasonal,
+        day_master,
+        support_share,
+        strength: DayMasterStrength::from_support_share(support_share),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seasonal_states_in_spring_and_earth_months() {
+        use Element::*;
+        let yin = EarthlyBranch::Yin;
+        assert_eq!(SeasonalState::of(Wood, yin), SeasonalState::Prosperous);
+        assert_eq!(SeasonalState::of(Fire, yin), SeasonalState::Strong);
+        assert_eq!(SeasonalState::of(Water, yin), SeasonalState::Resting);
+        assert_eq!(SeasonalState::of(Metal, yin), SeasonalState::Confined);
+        assert_eq!(SeasonalState::of(Earth, yin), SeasonalState::Dead);
+        assert_eq!(
+            SeasonalState::of(Earth, EarthlyBranch::Wei),
+            SeasonalState::Prosperous
+        );
+    }
+
+    #[test]
+    fn scores_for_known_chart() {
+        // 乙丑 庚辰 庚寅 庚辰: raw Wood 2.2, Fire 0.3, Earth 1.9, Metal 3.1, Water 0.5
+        // in a 辰 (Earth) month: Earth 旺, Metal 相, Fire 休, Wood 囚, Water 死.
+        let result = element_strength(&"乙丑 庚辰 庚寅 庚辰".parse().unwrap());
+        let expected = [1.65, 0.3, 2.85, 3.875, 0.25];
+        for (got, want) in result.scores.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-9, "{:?}", result.scores);
+        }
+        assert!((result.support_share - 6.725 / 8.925).abs() < 1e-9);
+        assert_eq!(result.strength, DayMasterStrength::Strong);
+        assert_eq!(
+            result.to_string(),
+            "木1.65(囚) 火0.3(休) 土2.85(旺) 金3.88(相) 水0.25(死) | 日主 庚金 身强 (75% support)"
+        );
+    }
+
+    #[test]
+    fn weak_day_master_out_of_season() {
+        // 丙 fire day master in a 子 (Water) month surrounded by water and metal.
+        let result = element_strength(&"庚申 戊子 丙子 辛亥".parse().unwrap());
+        assert_eq!(result.seasonal_state(Element::Fire), SeasonalState::Dead);
+        assert_eq!(result.strength, DayMasterStrength::Weak);
+    }
+
+    #[test]
+    fn custom_weights() {
+        let flat = StrengthWeights {
+            seasonal: [1.0; 5],
+            ..StrengthWeights::default()
+        };
+        let result = element_strength_with(&"乙丑 庚辰 庚寅 庚辰".parse().unwrap(), &flat);
+        let total: f64 = result.scores.iter().sum();
+        assert!((total - 8.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use std::fmt;
+
+/// Why a table lookup or calculation could not produce a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum LunarError {
+    /// The (lunar) year is outside the `BAZI_YEAR_DATA` table.
+    OutOfRange { year: i32, min: i32, max: i32 },
+    /// A field of `BAZI_YEAR_DATA` for `year` failed to parse.
+    CorruptTable { year: i32, field: &'static str },
+    /// `time` is in range, but the table has no solar term on one side of it
+    /// (e.g. after the last 小雪 of 2099).
+    NoSurroundingTerm { time: NaiveDateTime },
+    /// The lunar month is not 1..=12.
+    InvalidLunarMonth { month: u8 },
+    /// The lunar year has no leap month with this number.
+    NoLeapMonth { year: i32, month: u8 },
+    /// The day does not exist in that lunar month (e.g. the 30th of a 29-day month).
+    InvalidLunarDay {
+        year: i32,
+        month: u8,
+        is_leap: bool,
+        day: u8,
+        days_in_month: u8,
+    },
+}
+
+impl fmt::Display for LunarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LunarError::OutOfRange { year, min, max } => {
+                write!(
+                    f,
+                    "year {year} is outside the supported table range ({min}..={max})"
+                )
+            }
+            LunarError::CorruptTable { year, field } => {
+                write!(f, "table row {year} has an unparsable `{field}` field")
+            }
+            LunarError::NoSurroundingTerm { time } => {
+                write!(f, "no surrounding solar term in the table for {time}")
+            }
+            LunarError::InvalidLunarMonth { month } => {
+                write!(f, "invalid lunar month {month} (expected 1..=12)")
+            }
+            LunarError::NoLeapMonth { year, month } => {
+                write!(f, "lunar year {year} has no leap month {month}")
+            }
+            LunarError::InvalidLunarDay {
+                year,
+                month,
+                is_leap,
+                day,
+                days_in_month,
+            } => {
+                let leap = if *is_leap { "leap " } else { "" };
+                write!(
+                    f,
+                    "lunar {leap}month {month} of {year} has {days_in_month} days; day {day} does not exist"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LunarError {}
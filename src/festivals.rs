@@ -0,0 +1,357 @@
+use crate::bazi_calc::term_time;
+use crate::error::LunarError;
+use crate::lunar_date::{lunar_month_days, try_from_lunar_date};
+use crate::periods::{try_sanfu, try_shujiu};
+use crate::table_lookup::try_get_year_data;
+use chrono::{Datelike, NaiveDate};
+use std::fmt;
+
+/// What kind of day a [`Festival`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FestivalKind {
+    /// Traditional festival.
+    Festival,
+    /// Memorial day for ancestors (清明, 中元, 寒衣).
+    Memorial,
+    /// First day of a 三伏 or 数九 period.
+    Period,
+}
+
+/// Traditional festivals, memorial days and seasonal periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Festival {
+    /// 春节: 正月初一.
+    SpringFestival,
+    /// 元宵: 正月十五.
+    Lantern,
+    /// 端午: 五月初五.
+    DragonBoat,
+    /// 七夕: 七月初七.
+    Qixi,
+    /// 中元: 七月十五.
+    Zhongyuan,
+    /// 中秋: 八月十五.
+    MidAutumn,
+    /// 重阳: 九月初九.
+    DoubleNinth,
+    /// 寒衣: 十月初一.
+    WinterClothes,
+    /// 腊八: 腊月初八.
+    Laba,
+    /// 除夕: last day of 腊月, the 29th or 30th.
+    NewYearsEve,
+    /// 清明: day of the 清明 solar term.
+    Qingming,
+    /// 冬至: day of the 冬至 solar term.
+    WinterSolstice,
+    /// 初伏: third 庚 day from 夏至.
+    ChuFu,
+    /// 中伏: fourth 庚 day from 夏至.
+    ZhongFu,
+    /// 末伏: first 庚 day from 立秋.
+    MoFu,
+    /// First day of the n-th nine-day period (一九 ... 九九) counted from 冬至.
+    Nine(NineOrdinal),
+}
+
+/// Number of a 数九 period, always 1..=9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NineOrdinal(u8);
+
+impl NineOrdinal {
+    /// Returns `None` outside 1..=9.
+    pub fn new(n: u8) -> Option<NineOrdinal> {
+        (1..=9).contains(&n).then_some(NineOrdinal(n))
+    }
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+const NINE_NAMES: [&str; 9] = [
+    "一九", "二九", "三九", "四九", "五九", "六九", "七九", "八九", "九九",
+];
+
+impl Festival {
+    /// [`Festival::Nine`] for `n` in 1..=9.
+    pub fn nine(n: u8) -> Option<Festival> {
+        NineOrdinal::new(n).map(Festival::Nine)
+    }
+
+    pub fn kind(self) -> FestivalKind {
+        match self {
+            Festival::Qingming | Festival::Zhongyuan | Festival::WinterClothes => {
+                FestivalKind::Memorial
+            }
+            Festival::ChuFu | Festival::ZhongFu | Festival::MoFu | Festival::Nine(_) => {
+                FestivalKind::Period
+            }
+            _ => FestivalKind::Festival,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Festival::SpringFestival => "春节",
+            Festival::Lantern => "元宵",
+            Festival::DragonBoat => "端午",
+            Festival::Qixi => "七夕",
+            Festival::Zhongyuan => "中元",
+            Festival::MidAutumn => "中秋",
+            Festival::DoubleNinth => "重阳",
+            Festival::WinterClothes => "寒衣",
+            Festival::Laba => "腊八",
+            Festival::NewYearsEve => "除夕",
+            Festival::Qingming => "清明",
+            Festival::WinterSolstice => "冬至",
+            Festival::ChuFu => "初伏",
+            Festival::ZhongFu => "中伏",
+            Festival::MoFu => "末伏",
+            Festival::Nine(n) => NINE_NAMES[usize::from(n.get()) - 1],
+        }
+    }
+}
+
+impl fmt::Display for Festival {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A festival and the Gregorian date it falls on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FestivalDay {
+    pub date: NaiveDate,
+    pub festival: Festival,
+}
+
+impl fmt::Display for FestivalDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.date, self.festival)
+    }
+}
+
+/// Festivals on a fixed day of a regular (non-leap) lunar month.
+const LUNAR_FESTIVALS: [(Festival, u8, u8); 9] = [
+    (Festival::SpringFestival, 1, 1),
+    (Festival::Lantern, 1, 15),
+    (Festival::DragonBoat, 5, 5),
+    (Festival::Qixi, 7, 7),
+    (Festival::Zhongyuan, 7, 15),
+    (Festival::MidAutumn, 8, 15),
+    (Festival::DoubleNinth, 9, 9),
+    (Festival::WinterClothes, 10, 1),
+    (Festival::Laba, 12, 8),
+];
+
+/// `Ok(None)` when `result` failed only because its year is outside the table.
+fn unless_out_of_range<T>(result: Result<T, LunarError>) -> Result<Option<T>, LunarError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(LunarError::OutOfRange { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Lunar festivals of one lunar year, 除夕 last.
+fn lunar_year_festivals(year: i32) -> Result<Vec<FestivalDay>, LunarError> {
+    let mut days = Vec::with_capacity(LUNAR_FESTIVALS.len() + 1);
+    for (festival, month, day) in LUNAR_FESTIVALS {
+        days.push(FestivalDay {
+            date: try_from_lunar_date(year, month, day, false)?,
+            festival,
+        });
+    }
+    // 除夕 is the last day of the year's final month: the 29th or 30th of 腊月,
+    // or of 闰腊月 when the year has one.
+    let leap_last = try_get_year_data(year)?.leap_month == 12;
+    let last_day = lunar_month_days(year, 12, leap_last).ok_or(LunarError::CorruptTable {
+        year,
+        field: "new_moons",
+    })?;
+    days.push(FestivalDay {
+        date: try_from_lunar_date(year, 12, last_day, leap_last)?,
+        festival: Festival::NewYearsEve,
+    });
+    Ok(days)
+}
+
+/// Festivals, memorial days and period starts in Gregorian `year`, by date.
+///
+/// Empty when the year is outside the table.
+pub fn festivals_in(year: i32) -> Vec<FestivalDay> {
+    try_festivals_in(year).unwrap_or_default()
+}
+
+/// Like [`festivals_in`], but explains why the year is unavailable.
+///
+/// Days that come from a neighbouring year outside the table (腊八/除夕 of
+/// lunar 1899, 冬至 of 2099 and the nines after it) are left out. Period
+/// starts come from [`try_sanfu`] and [`try_shujiu`].
+pub fn try_festivals_in(year: i32) -> Result<Vec<FestivalDay>, LunarError> {
+    let mut days = lunar_year_festivals(year)?;
+    if let Some(previous) = unless_out_of_range(lunar_year_festivals(year - 1))? {
+        days.extend(previous);
+    }
+    for (name, festival) in [
+        ("清明", Festival::Qingming),
+        ("冬至", Festival::WinterSolstice),
+    ] {
+        if let Some(time) = unless_out_of_range(term_time(year, name))? {
+            days.push(FestivalDay {
+                date: time.date(),
+                festival,
+            });
+        }
+    }
+    let fu = try_sanfu(year)?;
+    days.extend([
+        FestivalDay {
+            date: fu.chu.start,
+            festival: Festival::ChuFu,
+        },
+        FestivalDay {
+            date: fu.zhong.start,
+            festival: Festival::ZhongFu,
+        },
+        FestivalDay {
+            date: fu.mo.start,
+            festival: Festival::MoFu,
+        },
+    ]);
+    for solstice_year in [year - 1, year] {
+        if let Some(winter) = unless_out_of_range(try_shujiu(solstice_year))? {
+            days.extend((1..=9).zip(winter.nines).map(|(n, nine)| FestivalDay {
+                date: nine.start,
+                festival: Festival::Nine(NineOrdinal(n)),
+            }));
+        }
+    }
+    days.retain(|day| day.date.year() == year);
+    days.sort_by_key(|day| day.date);
+    Ok(days)
+}
+
+/// Festivals falling on `date`; several can share a day (冬至 is also 一九).
+pub fn festival_on(date: NaiveDate) -> Vec<Festival> {
+    festivals_in(date.year())
+        .into_iter()
+        .filter(|day| day.date == date)
+        .map(|day| day.festival)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn find(days: &[FestivalDay], festival: Festival) -> Vec<NaiveDate> {
+        days.iter()
+            .filter(|d| d.festival == festival)
+            .map(|d| d.date)
+            .collect()
+    }
+
+    #[test]
+    fn festivals_2024() {
+        let days = festivals_in(2024);
+        assert_eq!(find(&days, Festival::SpringFestival), [date(2024, 2, 10)]);
+        assert_eq!(find(&days, Festival::Lantern), [date(2024, 2, 24)]);
+        assert_eq!(find(&days, Festival::Qingming), [date(2024, 4, 4)]);
+        assert_eq!(find(&days, Festival::DragonBoat), [date(2024, 6, 10)]);
+        assert_eq!(find(&days, Festival::Qixi), [date(2024, 8, 10)]);
+        assert_eq!(find(&days, Festival::MidAutumn), [date(2024, 9, 17)]);
+        assert_eq!(find(&days, Festival::DoubleNinth), [date(2024, 10, 11)]);
+        assert_eq!(find(&days, Festival::WinterSolstice), [date(2024, 12, 21)]);
+        // Lunar 2023's 腊八 and 除夕 fall in 2024; lunar 2024's fall in 2025.
+        assert_eq!(find(&days, Festival::Laba), [date(2024, 1, 18)]);
+        assert_eq!(find(&days, Festival::NewYearsEve), [date(2024, 2, 9)]);
+        assert!(days.windows(2).all(|w| w[0].date <= w[1].date));
+    }
+
+    #[test]
+    fn new_years_eve_on_29th_and_30th() {
+        // 腊月 of lunar 2023 has 30 days: 除夕 is 三十 (2024-02-09).
+        assert_eq!(lunar_month_days(2023, 12, false), Some(30));
+        assert_eq!(
+            find(&festivals_in(2024), Festival::NewYearsEve),
+            [date(2024, 2, 9)]
+        );
+        // 腊月 of lunar 2024 and 2025 has 29 days: 除夕 is 廿九.
+        assert_eq!(lunar_month_days(2024, 12, false), Some(29));
+        assert_eq!(
+            find(&festivals_in(2025), Festival::NewYearsEve),
+            [date(2025, 1, 28)]
+        );
+        assert_eq!(lunar_month_days(2025, 12, false), Some(29));
+        assert_eq!(
+            find(&festivals_in(2026), Festival::NewYearsEve),
+            [date(2026, 2, 16)]
+        );
+        for year in 1901..=2099 {
+            let eve = find(&festivals_in(year), Festival::NewYearsEve);
+            let spring = find(&festivals_in(year), Festival::SpringFestival);
+            assert_eq!(eve.len(), 1, "{year}");
+            assert_eq!(eve[0].succ_opt(), Some(spring[0]), "{year}");
+        }
+    }
+
+    #[test]
+    fn periods_2024() {
+        let days = festivals_in(2024);
+        assert_eq!(find(&days, Festival::ChuFu), [date(2024, 7, 15)]);
+        assert_eq!(find(&days, Festival::ZhongFu), [date(2024, 7, 25)]);
+        assert_eq!(find(&days, Festival::MoFu), [date(2024, 8, 14)]);
+        // 冬至 2023-12-22: 一九 and 二九 start in 2023, 三九 ... 九九 in 2024.
+        assert_eq!(find(&days, Festival::nine(3).unwrap()), [date(2024, 1, 9)]);
+        assert_eq!(find(&days, Festival::nine(9).unwrap()), [date(2024, 3, 3)]);
+        // 冬至 2024-12-21: 一九 and 二九 start in 2024.
+        assert_eq!(
+            find(&days, Festival::nine(1).unwrap()),
+            [date(2024, 12, 21)]
+        );
+        assert_eq!(
+            find(&days, Festival::nine(2).unwrap()),
+            [date(2024, 12, 30)]
+        );
+    }
+
+    #[test]
+    fn several_festivals_on_one_day() {
+        assert_eq!(
+            festival_on(date(2024, 12, 21)),
+            [Festival::WinterSolstice, Festival::nine(1).unwrap()]
+        );
+        assert_eq!(festival_on(date(2024, 2, 10)), [Festival::SpringFestival]);
+        assert!(festival_on(date(2024, 2, 11)).is_empty());
+        assert_eq!(Festival::Qingming.kind(), FestivalKind::Memorial);
+        assert_eq!(Festival::nine(3).unwrap().to_string(), "三九");
+        assert_eq!(Festival::nine(0), None);
+        assert_eq!(Festival::nine(10), None);
+    }
+
+    #[test]
+    fn table_edges() {
+        let first = festivals_in(1900);
+        // Lunar 1899 is outside the table and lunar 1900's 除夕 falls in 1901.
+        assert!(find(&first, Festival::NewYearsEve).is_empty());
+        assert!(find(&first, Festival::Laba).is_empty());
+        assert_eq!(find(&first, Festival::SpringFestival), [date(1900, 1, 31)]);
+        let last = festivals_in(2099);
+        assert!(find(&last, Festival::WinterSolstice).is_empty());
+        assert_eq!(find(&last, Festival::nine(9).unwrap()).len(), 1);
+        assert!(matches!(
+            try_festivals_in(2100),
+            Err(LunarError::OutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::bazi_calc::{month_pillar, solar_year_jie};
+use crate::error::LunarError;
+use crate::ganzhi::GanZhi;
+use crate::table_lookup::get_cycle_index;
+use chrono::NaiveDateTime;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Annual flow pillar (流年), in force from one LiChun to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlowYear {
+    pub year: i32,
+    pub pillar: GanZhi,
+    /// LiChun of `year` (UTC+8).
+    pub start: NaiveDateTime,
+    /// LiChun of the following year (UTC+8).
+    pub end: NaiveDateTime,
+}
+
+impl fmt::Display for FlowYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}年 ({} → {})",
+            self.year, self.pillar, self.start, self.end
+        )
+    }
+}
+
+/// Monthly flow pillar (流月), in force from its Jie to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FlowMonth {
+    pub pillar: GanZhi,
+    /// Jie opening the month, e.g. `立春`.
+    pub jie: &'static str,
+    /// Time of `jie` (UTC+8).
+    pub start: NaiveDateTime,
+    /// Time of the next Jie (UTC+8).
+    pub end: NaiveDateTime,
+}
+
+impl fmt::Display for FlowMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}月 {} ({} → {})",
+            self.pillar, self.jie, self.start, self.end
+        )
+    }
+}
+
+/// Annual flow pillar of a year, or why it is unavailable.
+///
+/// The last table year has no following LiChun and is reported as out of range.
+pub fn try_flow_year(year: i32) -> Result<FlowYear, LunarError> {
+    let jie = solar_year_jie(year)?;
+    Ok(FlowYear {
+        year,
+        pillar: GanZhi::from_index(get_cycle_index(year)),
+        start: jie[0].1,
+        end: jie[12].1,
+    })
+}
+
+/// Annual flow pillars for each year in `years` covered by the table.
+pub fn flow_years(years: RangeInclusive<i32>) -> impl Iterator<Item = FlowYear> {
+    years.filter_map(|year| try_flow_year(year).ok())
+}
+
+/// Like [`flow_months`], but explains why the months are unavailable.
+pub fn try_flow_months(year: i32) -> Result<Vec<FlowMonth>, LunarError> {
+    let jie = solar_year_jie(year)?;
+    let year_gz = GanZhi::from_index(get_cycle_index(year));
+    Ok(jie
+        .windows(2)
+        .enumerate()
+        .map(|(month_idx, pair)| FlowMonth {
+            pillar: month_pillar(year_gz, month_idx),
+            jie: pair[0].0,
+            start: pair[0].1,
+            end: pair[1].1,
+        })
+        .collect())
+}
+
+/// The twelve monthly flow pillars of the solar year starting at `year`'s LiChun,
+/// 寅 month first. Empty when the year is outside the table.
+pub fn flow_months(year: i32) -> impl Iterator<Item = FlowMonth> {
+    try_flow_months(year).unwrap_or_default().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::BAZI_YEAR_DATA;
+    use crate::table_lookup::{MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR};
+
+    fn parse_dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn flow_year_2027() {
+        let year = try_flow_year(2027).unwrap();
+        assert_eq!(year.pillar, "丁未");
+        assert_eq!(year.start, parse_dt("2027-02-04 09:46:18"));
+        assert_eq!(year.end, parse_dt(BAZI_YEAR_DATA[128].lichun_time));
+
+        let names: Vec<String> = flow_years(2026..=2028)
+            .map(|y| y.pillar.to_string())
+            .collect();
+        assert_eq!(names, ["丙午", "丁未", "戊申"]);
+    }
+
+    #[test]
+    fn flow_months_2027() {
+        let months: Vec<FlowMonth> = flow_months(2027).collect();
+        assert_eq!(months.len(), 12);
+        let names: Vec<String> = months.iter().map(|m| m.pillar.to_string()).collect();
+        assert_eq!(
+            names,
+            [
+                "壬寅", "癸卯", "甲辰", "乙巳", "丙午", "丁未", "戊申", "己酉", "庚戌", "辛亥",
+                "壬子", "癸丑"
+            ]
+        );
+        assert_eq!(months[0].jie, "立春");
+        assert_eq!(months[1].jie, "惊蛰");
+        assert_eq!(months[1].start, parse_dt("2027-03-06 03:39:33"));
+        assert_eq!(months[11].jie, "小寒");
+        for pair in months.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        assert_eq!(months[11].end, try_flow_year(2027).unwrap().end);
+    }
+
+    #[test]
+    fn flow_months_match_pillars_inside_each_month() {
+        for year in [1900, 1985, 2024, 2098] {
+            for month in flow_months(year) {
+                let mid = month.start + (month.end - month.start) / 2;
+                let pillars = crate::bazi_calc::get_pillars(mid).unwrap();
+                assert_eq!(pillars.month, month.pillar, "{year} {}", month.jie);
+            }
+        }
+    }
+
+    #[test]
+    fn table_edges() {
+        assert_eq!(
+            flow_years(MIN_SUPPORTED_YEAR - 1..=MAX_SUPPORTED_YEAR).count(),
+            199
+        );
+        assert!(matches!(
+            try_flow_months(MAX_SUPPORTED_YEAR),
+            Err(LunarError::OutOfRange { .. })
+        ));
+        assert_eq!(flow_months(MAX_SUPPORTED_YEAR).count(), 0);
+        for year in [i32::MIN, i32::MAX] {
+            assert!(matches!(
+                try_flow_months(year),
+                Err(LunarError::OutOfRange { year: y, .. }) if y == year
+            ));
+            assert!(matches!(
+                try_flow_year(year),
+                Err(LunarError::OutOfRange { year: y, .. }) if y == year
+            ));
+            assert_eq!(flow_months(year).count(), 0);
+        }
+    }
+}
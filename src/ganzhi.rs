@@ -0,0 +1,427 @@
+use crate::table_lookup::{BRANCHES, STEMS, get_nayin_by_index};
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// The five elements (五行).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Element {
+    Wood,
+    Fire,
+    Earth,
+    Metal,
+    Water,
+}
+
+impl Element {
+    pub const ALL: [Element; 5] = [
+        Element::Wood,
+        Element::Fire,
+        Element::Earth,
+        Element::Metal,
+        Element::Water,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Element::Wood => "木",
+            Element::Fire => "火",
+            Element::Earth => "土",
+            Element::Metal => "金",
+            Element::Water => "水",
+        }
+    }
+
+    /// The element this one produces (生): Wood -> Fire -> Earth -> Metal -> Water -> Wood.
+    pub fn generates(self) -> Element {
+        Element::ALL[(self as usize + 1) % 5]
+    }
+
+    /// The element this one overcomes (克): Wood -> Earth -> Water -> Fire -> Metal -> Wood.
+    pub fn overcomes(self) -> Element {
+        Element::ALL[(self as usize + 2) % 5]
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Polarity (阴阳) of a stem or branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YinYang {
+    Yang,
+    Yin,
+}
+
+impl YinYang {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            YinYang::Yang => "阳",
+            YinYang::Yin => "阴",
+        }
+    }
+}
+
+impl fmt::Display for YinYang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when a stem, branch or 干支 string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseGanZhiError {
+    input: String,
+}
+
+impl fmt::Display for ParseGanZhiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid stem/branch: '{}'", self.input)
+    }
+}
+
+impl std::error::Error for ParseGanZhiError {}
+
+pub(crate) fn parse_error(input: &str) -> ParseGanZhiError {
+    ParseGanZhiError {
+        input: input.to_string(),
+    }
+}
+
+/// Heavenly Stems (天干), in cycle order starting from 甲.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HeavenlyStem {
+    Jia,
+    Yi,
+    Bing,
+    Ding,
+    Wu,
+    Ji,
+    Geng,
+    Xin,
+    Ren,
+    Gui,
+}
+
+impl HeavenlyStem {
+    pub const ALL: [HeavenlyStem; 10] = [
+        HeavenlyStem::Jia,
+        HeavenlyStem::Yi,
+        HeavenlyStem::Bing,
+        HeavenlyStem::Ding,
+        HeavenlyStem::Wu,
+        HeavenlyStem::Ji,
+        HeavenlyStem::Geng,
+        HeavenlyStem::Xin,
+        HeavenlyStem::Ren,
+        HeavenlyStem::Gui,
+    ];
+
+    /// Builds a stem from any index, wrapping modulo 10 (0 = 甲).
+    pub fn from_index(index: usize) -> HeavenlyStem {
+        Self::ALL[index % 10]
+    }
+
+    /// Position in the stem cycle, 0..=9.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn as_str(self) -> &'static str {
+        STEMS[self.index()]
+    }
+
+    /// 甲乙 Wood, 丙丁 Fire, 戊己 Earth, 庚辛 Metal, 壬癸 Water.
+    pub fn element(self) -> Element {
+        Element::ALL[self.index() / 2]
+    }
+
+    /// Even positions (甲丙戊庚壬) are yang, odd positions are yin.
+    pub fn yin_yang(self) -> YinYang {
+        if self.index().is_multiple_of(2) {
+            YinYang::Yang
+        } else {
+            YinYang::Yin
+        }
+    }
+}
+
+impl Add<i32> for HeavenlyStem {
+    type Output = HeavenlyStem;
+
+    fn add(self, n: i32) -> HeavenlyStem {
+        Self::from_index((self.index() as i32 + n).rem_euclid(10) as usize)
+    }
+}
+
+impl fmt::Display for HeavenlyStem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for HeavenlyStem {
+    type Err = ParseGanZhiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        STEMS
+            .iter()
+            .position(|stem| *stem == s)
+            .map(Self::from_index)
+            .ok_or_else(|| parse_error(s))
+    }
+}
+
+/// Earthly Branches (地支), in cycle order starting from 子.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EarthlyBranch {
+    Zi,
+    Chou,
+    Yin,
+    Mao,
+    Chen,
+    Si,
+    Wu,
+    Wei,
+    Shen,
+    You,
+    Xu,
+    Hai,
+}
+
+impl EarthlyBranch {
+    pub const ALL: [EarthlyBranch; 12] = [
+        EarthlyBranch::Zi,
+        EarthlyBranch::Chou,
+        EarthlyBranch::Yin,
+        EarthlyBranch::Mao,
+        EarthlyBranch::Chen,
+        EarthlyBranch::Si,
+        EarthlyBranch::Wu,
+        EarthlyBranch::Wei,
+        EarthlyBranch::Shen,
+        EarthlyBranch::You,
+        EarthlyBranch::Xu,
+        EarthlyBranch::Hai,
+    ];
+
+    /// Builds a branch from any index, wrapping modulo 12 (0 = 子).
+    pub fn from_index(index: usize) -> EarthlyBranch {
+        Self::ALL[index % 12]
+    }
+
+    /// Position in the branch cycle, 0..=11.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn as_str(self) -> &'static str {
+        BRANCHES[self.index()]
+    }
+
+    /// Element of the branch's main qi: 寅卯 Wood, 巳午 Fire, 申酉 Metal, 亥子 Water, 辰戌丑未 Earth.
+    pub fn element(self) -> Element {
+        match self {
+            EarthlyBranch::Yin | EarthlyBranch::Mao => Element::Wood,
+            EarthlyBranch::Si | EarthlyBranch::Wu => Element::Fire,
+            EarthlyBranch::Shen | EarthlyBranch::You => Element::Metal,
+            EarthlyBranch::Hai | EarthlyBranch::Zi => Element::Water,
+            EarthlyBranch::Chen | EarthlyBranch::Xu | EarthlyBranch::Chou | EarthlyBranch::Wei => {
+                Element::Earth
+            }
+        }
+    }
+
+    /// Even positions (子寅辰午申戌) are yang, odd positions are yin.
+    pub fn yin_yang(self) -> YinYang {
+        if self.index().is_multiple_of(2) {
+            YinYang::Yang
+        } else {
+            YinYang::Yin
+        }
+    }
+
+    /// Zodiac animal (生肖) of the branch: 子 鼠, 丑 牛, ... 亥 猪.
+    pub fn zodiac(self) -> &'static str {
+        ZODIAC[self.index()]
+    }
+}
+
+const ZODIAC: [&str; 12] = [
+    "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
+];
+
+impl Add<i32> for EarthlyBranch {
+    type Output = EarthlyBranch;
+
+    fn add(self, n: i32) -> EarthlyBranch {
+        Self::from_index((self.index() as i32 + n).rem_euclid(12) as usize)
+    }
+}
+
+impl fmt::Display for EarthlyBranch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EarthlyBranch {
+    type Err = ParseGanZhiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BRANCHES
+            .iter()
+            .position(|branch| *branch == s)
+            .map(Self::from_index)
+            .ok_or_else(|| parse_error(s))
+    }
+}
+
+/// A stem-branch pair (干支) of the 60 JiaZi cycle, where 甲子 is index 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GanZhi(u8);
+
+impl GanZhi {
+    /// Builds a 干支 from any index, wrapping modulo 60 (0 = 甲子).
+    pub fn from_index(index: usize) -> GanZhi {
+        GanZhi((index % 60) as u8)
+    }
+
+    /// Pairs a stem with a branch; `None` if their polarities differ (e.g. 甲丑).
+    pub fn from_stem_branch(stem: HeavenlyStem, branch: EarthlyBranch) -> Option<GanZhi> {
+        if stem.index() % 2 != branch.index() % 2 {
+            return None;
+        }
+        // Solve idx ≡ stem (mod 10), idx ≡ branch (mod 12).
+        let idx = (6 * stem.index() as i32 - 5 * branch.index() as i32).rem_euclid(60);
+        Some(GanZhi(idx as u8))
+    }
+
+    /// Position in the 60 JiaZi cycle, 0..=59.
+    pub fn cycle_index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub fn stem(self) -> HeavenlyStem {
+        HeavenlyStem::from_index(self.cycle_index())
+    }
+
+    pub fn branch(self) -> EarthlyBranch {
+        EarthlyBranch::from_index(self.cycle_index())
+    }
+
+    /// NaYin (纳音五行) of this 干支.
+    pub fn nayin(self) -> &'static str {
+        // Safe for 0..=59 indices held by GanZhi.
+        get_nayin_by_index(self.cycle_index()).unwrap_or_default()
+    }
+
+    /// Head of the 旬 (decade) containing this 干支: 甲子, 甲戌, 甲申, 甲午, 甲辰 or 甲寅.
+    pub fn xun_head(self) -> GanZhi {
+        GanZhi::from_index(self.cycle_index() / 10 * 10)
+    }
+
+    /// The two branches left unpaired in this 干支's 旬 (旬空/空亡), e.g. 戌亥 for 甲子旬.
+    pub fn void_branches(self) -> [EarthlyBranch; 2] {
+        // Each 旬 pairs ten branches starting at its head's; the next two are void.
+        let first = self.xun_head().branch() + 10;
+        [first, first + 1]
+    }
+}
+
+impl Add<i32> for GanZhi {
+    type Output = GanZhi;
+
+    fn add(self, n: i32) -> GanZhi {
+        Self::from_index((self.cycle_index() as i32 + n).rem_euclid(60) as usize)
+    }
+}
+
+impl fmt::Display for GanZhi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.stem(), self.branch())
+    }
+}
+
+impl FromStr for GanZhi {
+    type Err = ParseGanZhiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let (Some(stem), Some(branch), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(parse_error(s));
+        };
+        let stem: HeavenlyStem = stem.to_string().parse().map_err(|_| parse_error(s))?;
+        let branch: EarthlyBranch = branch.to_string().parse().map_err(|_| parse_error(s))?;
+        Self::from_stem_branch(stem, branch).ok_or_else(|| parse_error(s))
+    }
+}
+
+impl PartialEq<&str> for GanZhi {
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<GanZhi>().is_ok_and(|gz| gz == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ganzhi_round_trips_through_strings() {
+        for i in 0..60 {
+            let gz = GanZhi::from_index(i);
+            assert_eq!(gz.cycle_index(), i);
+            assert_eq!(gz.to_string().parse::<GanZhi>(), Ok(gz));
+            assert_eq!(GanZhi::from_stem_branch(gz.stem(), gz.branch()), Some(gz));
+        }
+        assert_eq!(GanZhi::from_index(10), "甲戌");
+        assert!("甲丑".parse::<GanZhi>().is_err());
+        assert!("甲".parse::<GanZhi>().is_err());
+    }
+
+    #[test]
+    fn xun_heads_and_void_branches() {
+        let cases = [
+            ("甲子", "甲子", "戌亥"),
+            ("癸酉", "甲子", "戌亥"),
+            ("甲戌", "甲戌", "申酉"),
+            ("庚寅", "甲申", "午未"),
+            ("丙申", "甲午", "辰巳"),
+            ("己酉", "甲辰", "寅卯"),
+            ("癸亥", "甲寅", "子丑"),
+        ];
+        for (input, head, void) in cases {
+            let gz: GanZhi = input.parse().unwrap();
+            assert_eq!(gz.xun_head(), head, "{input}");
+            let [a, b] = gz.void_branches();
+            assert_eq!(format!("{a}{b}"), void, "{input}");
+        }
+    }
+
+    #[test]
+    fn arithmetic_wraps() {
+        assert_eq!(HeavenlyStem::Gui + 1, HeavenlyStem::Jia);
+        assert_eq!(HeavenlyStem::Jia + -1, HeavenlyStem::Gui);
+        assert_eq!(EarthlyBranch::Hai + 3, EarthlyBranch::Yin);
+        assert_eq!(GanZhi::from_index(59) + 1, GanZhi::from_index(0));
+        assert_eq!("甲子".parse::<GanZhi>().unwrap() + -1, "癸亥");
+    }
+
+    #[test]
+    fn element_and_polarity() {
+        assert_eq!(HeavenlyStem::Bing.element(), Element::Fire);
+        assert_eq!(HeavenlyStem::Ding.yin_yang(), YinYang::Yin);
+        assert_eq!(EarthlyBranch::Chou.element(), Element::Earth);
+        assert_eq!(EarthlyBranch::Wu.yin_yang(), YinYang::Yang);
+        assert_eq!(EarthlyBranch::Zi.zodiac(), "鼠");
+        assert_eq!(EarthlyBranch::Xu.zodiac(), "狗");
+        assert_eq!(Element::Wood.generates(), Element::Fire);
+        assert_eq!(Element::Wood.overcomes(), Element::Earth);
+        assert_eq!(GanZhi::from_index(0).nayin(), "海中金");
+    }
+}
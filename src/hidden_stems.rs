@@ -0,0 +1,166 @@
+use crate::ganzhi::{EarthlyBranch, HeavenlyStem};
+use std::fmt;
+
+use HeavenlyStem::*;
+
+/// Role of a hidden stem inside its branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HiddenQi {
+    /// 本气: the branch's own element.
+    Main,
+    /// 中气
+    Middle,
+    /// 余气
+    Residual,
+}
+
+impl HiddenQi {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HiddenQi::Main => "本气",
+            HiddenQi::Middle => "中气",
+            HiddenQi::Residual => "余气",
+        }
+    }
+}
+
+impl fmt::Display for HiddenQi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A stem hidden in an Earthly Branch (地支藏干).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HiddenStem {
+    pub stem: HeavenlyStem,
+    pub qi: HiddenQi,
+}
+
+impl fmt::Display for HiddenStem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.stem, self.qi)
+    }
+}
+
+/// Relative strengths of the hidden stems, by how many stems a branch holds.
+///
+/// Each row should sum to 1.0; single-stem branches (子 卯 酉) always weigh 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HiddenStemWeights {
+    /// Main and middle qi of 午 and 亥.
+    pub two: [f64; 2],
+    /// Main, middle and residual qi of the other eight branches.
+    pub three: [f64; 3],
+}
+
+impl Default for HiddenStemWeights {
+    /// The common 6:3:1 split (7:3 for two-stem branches).
+    fn default() -> Self {
+        HiddenStemWeights {
+            two: [0.7, 0.3],
+            three: [0.6, 0.3, 0.1],
+        }
+    }
+}
+
+const fn main(stem: HeavenlyStem) -> HiddenStem {
+    HiddenStem {
+        stem,
+        qi: HiddenQi::Main,
+    }
+}
+
+const fn middle(stem: HeavenlyStem) -> HiddenStem {
+    HiddenStem {
+        stem,
+        qi: HiddenQi::Middle,
+    }
+}
+
+const fn residual(stem: HeavenlyStem) -> HiddenStem {
+    HiddenStem {
+        stem,
+        qi: HiddenQi::Residual,
+    }
+}
+
+/// Stems hidden in each branch, main qi first, indexed by branch.
+const HIDDEN_STEMS: [&[HiddenStem]; 12] = [
+    &[main(Gui)],                              // 子
+    &[main(Ji), middle(Gui), residual(Xin)],   // 丑
+    &[main(Jia), middle(Bing), residual(Wu)],  // 寅
+    &[main(Yi)],                               // 卯
+    &[main(Wu), middle(Yi), residual(Gui)],    // 辰
+    &[main(Bing), middle(Geng), residual(Wu)], // 巳
+    &[main(Ding), middle(Ji)],                 // 午
+    &[main(Ji), middle(Ding), residual(Yi)],   // 未
+    &[main(Geng), middle(Ren), residual(Wu)],  // 申
+    &[main(Xin)],                              // 酉
+    &[main(Wu), middle(Xin), residual(Ding)],  // 戌
+    &[main(Ren), middle(Jia)],                 // 亥
+];
+
+/// Hidden stems of a branch in main, middle, residual order.
+pub fn hidden_stems(branch: EarthlyBranch) -> &'static [HiddenStem] {
+    HIDDEN_STEMS[branch.index()]
+}
+
+/// Hidden stems of a branch paired with their weight under `weights`.
+pub fn weighted_hidden_stems(
+    branch: EarthlyBranch,
+    weights: &HiddenStemWeights,
+) -> Vec<(HiddenStem, f64)> {
+    let stems = hidden_stems(branch);
+    let row: &[f64] = match stems.len() {
+        1 => &[1.0],
+        2 => &weights.two,
+        _ => &weights.three,
+    };
+    stems.iter().copied().zip(row.iter().copied()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn main_qi_matches_branch_element() {
+        for branch in EarthlyBranch::ALL {
+            let stems = hidden_stems(branch);
+            assert_eq!(stems[0].qi, HiddenQi::Main);
+            assert_eq!(stems[0].stem.element(), branch.element(), "{branch}");
+        }
+    }
+
+    #[test]
+    fn hidden_stems_of_chou_and_wu() {
+        let chou: Vec<String> = hidden_stems(EarthlyBranch::Chou)
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+        assert_eq!(chou, ["己(本气)", "癸(中气)", "辛(余气)"]);
+        let wu: Vec<_> = hidden_stems(EarthlyBranch::Wu)
+            .iter()
+            .map(|h| h.stem)
+            .collect();
+        assert_eq!(wu, [Ding, Ji]);
+    }
+
+    #[test]
+    fn default_weights_sum_to_one() {
+        let weights = HiddenStemWeights::default();
+        for branch in EarthlyBranch::ALL {
+            let total: f64 = weighted_hidden_stems(branch, &weights)
+                .iter()
+                .map(|(_, w)| w)
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9, "{branch}: {total}");
+        }
+        let hai = weighted_hidden_stems(EarthlyBranch::Hai, &weights);
+        assert_eq!(hai[1], (middle(Jia), 0.3));
+    }
+}
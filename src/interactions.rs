@@ -0,0 +1,407 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{EarthlyBranch, Element, GanZhi};
+use std::fmt;
+
+/// Where a stem or branch sits: one of the four pillars or an extra pillar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PillarPosition {
+    Year,
+    Month,
+    Day,
+    Hour,
+    /// 大运
+    Luck,
+    /// 流年
+    Annual,
+}
+
+impl PillarPosition {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PillarPosition::Year => "年",
+            PillarPosition::Month => "月",
+            PillarPosition::Day => "日",
+            PillarPosition::Hour => "时",
+            PillarPosition::Luck => "运",
+            PillarPosition::Annual => "岁",
+        }
+    }
+}
+
+impl fmt::Display for PillarPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Which of the punishments (刑) a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PunishmentKind {
+    /// 恃势之刑: 寅巳申.
+    Bullying,
+    /// 无恩之刑: 丑戌未.
+    Ungrateful,
+    /// 无礼之刑: 子卯.
+    Rude,
+    /// 自刑: 辰辰, 午午, 酉酉, 亥亥.
+    SelfPunishment,
+}
+
+impl PunishmentKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PunishmentKind::Bullying => "恃势之刑",
+            PunishmentKind::Ungrateful => "无恩之刑",
+            PunishmentKind::Rude => "无礼之刑",
+            PunishmentKind::SelfPunishment => "自刑",
+        }
+    }
+}
+
+/// Kind of stem or branch interaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InteractionKind {
+    /// 天干五合, transforming into `element`.
+    StemCombination { element: Element },
+    /// 六合
+    SixCombination { element: Element },
+    /// 六冲
+    Clash,
+    /// 六害
+    Harm,
+    /// 刑
+    Punishment(PunishmentKind),
+    /// 破
+    Destruction,
+    /// 三合局: all three branches present.
+    ThreeHarmony { element: Element },
+    /// 半合: the frame's 子午卯酉 center and one other member.
+    HalfHarmony { element: Element },
+    /// 三会方: three branches of one season.
+    Directional { element: Element },
+}
+
+impl fmt::Display for InteractionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InteractionKind::StemCombination { element } => write!(f, "天干五合(化{element})"),
+            InteractionKind::SixCombination { element } => write!(f, "六合({element})"),
+            InteractionKind::Clash => f.write_str("六冲"),
+            InteractionKind::Harm => f.write_str("六害"),
+            InteractionKind::Punishment(kind) => write!(f, "相刑({})", kind.as_str()),
+            InteractionKind::Destruction => f.write_str("相破"),
+            InteractionKind::ThreeHarmony { element } => write!(f, "三合({element}局)"),
+            InteractionKind::HalfHarmony { element } => write!(f, "半合({element}局)"),
+            InteractionKind::Directional { element } => write!(f, "三会({element}方)"),
+        }
+    }
+}
+
+/// One detected interaction and the pillars taking part in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interaction {
+    pub kind: InteractionKind,
+    /// Participating stems or branches, e.g. `甲己` or `申子辰`.
+    pub members: String,
+    /// Pillars contributing a member, in chart order.
+    pub positions: Vec<PillarPosition>,
+}
+
+impl Interaction {
+    fn is_stem(&self) -> bool {
+        matches!(self.kind, InteractionKind::StemCombination { .. })
+    }
+}
+
+impl fmt::Display for Interaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = if self.is_stem() { "干" } else { "支" };
+        let positions: Vec<String> = self
+            .positions
+            .iter()
+            .map(|p| format!("{p}{part}"))
+            .collect();
+        write!(
+            f,
+            "{} {} ({})",
+            self.kind,
+            self.members,
+            positions.join(", ")
+        )
+    }
+}
+
+/// Three-harmony frames (三合) as (first branch, element); members are `b`, `b + 4`, `b + 8`.
+const THREE_HARMONY: [(usize, Element); 4] = [
+    (8, Element::Water), // 申子辰
+    (11, Element::Wood), // 亥卯未
+    (2, Element::Fire),  // 寅午戌
+    (5, Element::Metal), // 巳酉丑
+];
+
+/// Directional frames (三会) as (first branch, element); members are `b`, `b + 1`, `b + 2`.
+const DIRECTIONAL: [(usize, Element); 4] = [
+    (2, Element::Wood),   // 寅卯辰
+    (5, Element::Fire),   // 巳午未
+    (8, Element::Metal),  // 申酉戌
+    (11, Element::Water), // 亥子丑
+];
+
+/// Element of each 六合 pair, indexed by the lower branch index of the pair
+/// (子丑 土, 寅亥 木, 卯戌 火, 辰酉 金, 巳申 水, 午未 土).
+fn six_combination_element(a: usize, b: usize) -> Element {
+    match a.min(b) {
+        0 => Element::Earth,
+        2 => Element::Wood,
+        3 => Element::Fire,
+        4 => Element::Metal,
+        5 => Element::Water,
+        _ => Element::Earth,
+    }
+}
+
+/// 破 pairs: 子酉, 卯午, 辰丑, 未戌, 寅亥, 巳申 (each yang branch with the one nine places on).
+fn is_destruction(a: usize, b: usize) -> bool {
+    let (yang, yin) = if a.is_multiple_of(2) { (a, b) } else { (b, a) };
+    yang.is_multiple_of(2) && (yang + 9) % 12 == yin
+}
+
+fn punishment(a: usize, b: usize) -> Option<PunishmentKind> {
+    const BULLYING: [usize; 3] = [2, 5, 8]; // 寅巳申
+    const UNGRATEFUL: [usize; 3] = [1, 10, 7]; // 丑戌未
+    if a == b {
+        return [4, 6, 9, 11]
+            .contains(&a)
+            .then_some(PunishmentKind::SelfPunishment);
+    }
+    if BULLYING.contains(&a) && BULLYING.contains(&b) {
+        Some(PunishmentKind::Bullying)
+    } else if UNGRATEFUL.contains(&a) && UNGRATEFUL.contains(&b) {
+        Some(PunishmentKind::Ungrateful)
+    } else if (a, b) == (0, 3) || (a, b) == (3, 0) {
+        Some(PunishmentKind::Rude)
+    } else {
+        None
+    }
+}
+
+fn members(branches: &[usize]) -> String {
+    branches
+        .iter()
+        .map(|&b| EarthlyBranch::from_index(b).as_str())
+        .collect()
+}
+
+/// Interactions among the four pillars of a chart.
+pub fn interactions(pillars: &BaziPillars) -> Vec<Interaction> {
+    interactions_with(pillars, &[])
+}
+
+/// Interactions among the four pillars and `extra` pillars such as the current
+/// luck pillar (`PillarPosition::Luck`) or annual pillar (`PillarPosition::Annual`).
+///
+/// Findings come in a fixed order: stem combinations, branch pairs
+/// (六合, 六冲, 六害, 刑, 破), then three-branch frames (三合, 半合, 三会).
+pub fn interactions_with(
+    pillars: &BaziPillars,
+    extra: &[(PillarPosition, GanZhi)],
+) -> Vec<Interaction> {
+    let mut chart = vec![
+        (PillarPosition::Year, pillars.year),
+        (PillarPosition::Month, pillars.month),
+        (PillarPosition::Day, pillars.day),
+        (PillarPosition::Hour, pillars.hour),
+    ];
+    chart.extend_from_slice(extra);
+
+    let mut found = Vec::new();
+
+    // 天干五合: stems five apart (甲己, 乙庚, 丙辛, 丁壬, 戊癸).
+    for (i, &(pos_a, gz_a)) in chart.iter().enumerate() {
+        for &(pos_b, gz_b) in &chart[i + 1..] {
+            let (a, b) = (gz_a.stem().index(), gz_b.stem().index());
+            if (a + 5) % 10 == b {
+                let first = a.min(b);
+                found.push(Interaction {
+                    kind: InteractionKind::StemCombination {
+                        element: Element::ALL[(first + 2) % 5],
+                    },
+                    members: format!("{}{}", gz_a.stem(), gz_b.stem()),
+                    positions: vec![pos_a, pos_b],
+                });
+            }
+        }
+    }
+
+    // Branch pairs.
+    let branches: Vec<(PillarPosition, usize)> = chart
+        .iter()
+        .map(|&(pos, gz)| (pos, gz.branch().index()))
+        .collect();
+    for (i, &(pos_a, a)) in branches.iter().enumerate() {
+        for &(pos_b, b) in &branches[i + 1..] {
+            let mut pair = |kind| {
+                found.push(Interaction {
+                    kind,
+                    members: members(&[a, b]),
+                    positions: vec![pos_a, pos_b],
+                })
+            };
+            if (a + b) % 12 == 1 {
+                pair(InteractionKind::SixCombination {
+                    element: six_combination_element(a, b),
+                });
+            }
+            if (a + 12 - b) % 12 == 6 {
+                pair(InteractionKind::Clash);
+            }
+            if (a + b) % 12 == 7 {
+                pair(InteractionKind::Harm);
+            }
+            if let Some(kind) = punishment(a, b) {
+                pair(InteractionKind::Punishment(kind));
+            }
+            if a != b && is_destruction(a, b) {
+                pair(InteractionKind::Destruction);
+            }
+        }
+    }
+
+    // Three-branch frames.
+    let positions_of = |branch: usize| -> Vec<PillarPosition> {
+        branches
+            .iter()
+            .filter(|(_, b)| *b == branch)
+            .map(|(pos, _)| *pos)
+            .collect()
+    };
+    let frame = |kind, frame_members: &[usize]| {
+        let mut positions: Vec<PillarPosition> = frame_members
+            .iter()
+            .flat_map(|&b| positions_of(b))
+            .collect();
+        positions.sort();
+        Interaction {
+            kind,
+            members: members(frame_members),
+            positions,
+        }
+    };
+    for (first, element) in THREE_HARMONY {
+        let trio = [first, (first + 4) % 12, (first + 8) % 12];
+        let present = trio.map(|b| !positions_of(b).is_empty());
+        if present.iter().all(|&p| p) {
+            found.push(frame(InteractionKind::ThreeHarmony { element }, &trio));
+        } else if present[1] && present[0] {
+            found.push(frame(InteractionKind::HalfHarmony { element }, &trio[..2]));
+        } else if present[1] && present[2] {
+            found.push(frame(InteractionKind::HalfHarmony { element }, &trio[1..]));
+        }
+    }
+    for (first, element) in DIRECTIONAL {
+        let trio = [first, (first + 1) % 12, (first + 2) % 12];
+        if trio.iter().all(|&b| !positions_of(b).is_empty()) {
+            found.push(frame(InteractionKind::Directional { element }, &trio));
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(found: &[Interaction]) -> Vec<String> {
+        found.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn known_chart() {
+        // 乙丑 庚辰 庚寅 庚辰
+        let found = interactions(&"乙丑 庚辰 庚寅 庚辰".parse().unwrap());
+        assert_eq!(
+            rendered(&found),
+            [
+                "天干五合(化金) 乙庚 (年干, 月干)",
+                "天干五合(化金) 乙庚 (年干, 日干)",
+                "天干五合(化金) 乙庚 (年干, 时干)",
+                "相破 丑辰 (年支, 月支)",
+                "相破 丑辰 (年支, 时支)",
+                "相刑(自刑) 辰辰 (月支, 时支)",
+            ]
+        );
+    }
+
+    #[test]
+    fn branch_pairs() {
+        let found = interactions(&"甲子 丁卯 庚午 丙戌".parse().unwrap());
+        let kinds: Vec<(InteractionKind, &str)> =
+            found.iter().map(|i| (i.kind, i.members.as_str())).collect();
+        assert!(kinds.contains(&(InteractionKind::Clash, "子午")));
+        assert!(kinds.contains(&(InteractionKind::Punishment(PunishmentKind::Rude), "子卯")));
+        assert!(kinds.contains(&(InteractionKind::Destruction, "卯午")));
+        assert!(kinds.contains(&(
+            InteractionKind::SixCombination {
+                element: Element::Fire
+            },
+            "卯戌"
+        )));
+        assert!(kinds.contains(&(
+            InteractionKind::HalfHarmony {
+                element: Element::Fire
+            },
+            "午戌"
+        )));
+    }
+
+    #[test]
+    fn frames_and_extra_pillars() {
+        let pillars: BaziPillars = "壬申 壬子 甲寅 丙寅".parse().unwrap();
+        let found = interactions(&pillars);
+        assert!(found.iter().any(|i| i.kind
+            == InteractionKind::HalfHarmony {
+                element: Element::Water
+            }));
+
+        // A 戊辰 annual pillar completes 申子辰, replacing the half frame.
+        let annual: GanZhi = "戊辰".parse().unwrap();
+        let found = interactions_with(&pillars, &[(PillarPosition::Annual, annual)]);
+        let frame = found
+            .iter()
+            .find(|i| {
+                i.kind
+                    == InteractionKind::ThreeHarmony {
+                        element: Element::Water,
+                    }
+            })
+            .unwrap();
+        assert_eq!(frame.members, "申子辰");
+        assert_eq!(
+            frame.positions,
+            [
+                PillarPosition::Year,
+                PillarPosition::Month,
+                PillarPosition::Annual
+            ]
+        );
+        assert!(
+            found
+                .iter()
+                .all(|i| !matches!(i.kind, InteractionKind::HalfHarmony { .. }))
+        );
+    }
+
+    #[test]
+    fn directional_frame_and_bullying_punishment() {
+        let found = interactions(&"丙寅 辛卯 壬辰 己巳".parse().unwrap());
+        assert!(rendered(&found).contains(&"三会(木方) 寅卯辰 (年支, 月支, 日支)".to_string()));
+        assert!(rendered(&found).contains(&"天干五合(化水) 丙辛 (年干, 月干)".to_string()));
+        assert!(rendered(&found).contains(&"相刑(恃势之刑) 寅巳 (年支, 时支)".to_string()));
+        assert!(rendered(&found).contains(&"六害 寅巳 (年支, 时支)".to_string()));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{EarthlyBranch, HeavenlyStem, YinYang};
+use std::fmt;
+
+/// The twelve life stages (十二长生), in cycle order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LifeStage {
+    /// 长生
+    ChangSheng,
+    /// 沐浴
+    MuYu,
+    /// 冠带
+    GuanDai,
+    /// 临官
+    LinGuan,
+    /// 帝旺
+    DiWang,
+    /// 衰
+    Shuai,
+    /// 病
+    Bing,
+    /// 死
+    Si,
+    /// 墓
+    Mu,
+    /// 绝
+    Jue,
+    /// 胎
+    Tai,
+    /// 养
+    Yang,
+}
+
+impl LifeStage {
+    pub const ALL: [LifeStage; 12] = [
+        LifeStage::ChangSheng,
+        LifeStage::MuYu,
+        LifeStage::GuanDai,
+        LifeStage::LinGuan,
+        LifeStage::DiWang,
+        LifeStage::Shuai,
+        LifeStage::Bing,
+        LifeStage::Si,
+        LifeStage::Mu,
+        LifeStage::Jue,
+        LifeStage::Tai,
+        LifeStage::Yang,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LifeStage::ChangSheng => "长生",
+            LifeStage::MuYu => "沐浴",
+            LifeStage::GuanDai => "冠带",
+            LifeStage::LinGuan => "临官",
+            LifeStage::DiWang => "帝旺",
+            LifeStage::Shuai => "衰",
+            LifeStage::Bing => "病",
+            LifeStage::Si => "死",
+            LifeStage::Mu => "墓",
+            LifeStage::Jue => "绝",
+            LifeStage::Tai => "胎",
+            LifeStage::Yang => "养",
+        }
+    }
+}
+
+impl fmt::Display for LifeStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How yin stems run through the twelve stages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LifeStageSchool {
+    /// 阳顺阴逆: yin stems start at their own 长生 (乙午, 丁己酉, 辛子, 癸卯) and run backward.
+    #[default]
+    YinReversed,
+    /// 阴阳同生同死: yin stems share the stages of the yang stem of their element.
+    YinYangSame,
+}
+
+/// 长生 branch of each stem under [`LifeStageSchool::YinReversed`], indexed by stem.
+/// Earth follows Fire (戊 with 丙, 己 with 丁).
+const CHANG_SHENG: [EarthlyBranch; 10] = [
+    EarthlyBranch::Hai,  // 甲
+    EarthlyBranch::Wu,   // 乙
+    EarthlyBranch::Yin,  // 丙
+    EarthlyBranch::You,  // 丁
+    EarthlyBranch::Yin,  // 戊
+    EarthlyBranch::You,  // 己
+    EarthlyBranch::Si,   // 庚
+    EarthlyBranch::Zi,   // 辛
+    EarthlyBranch::Shen, // 壬
+    EarthlyBranch::Mao,  // 癸
+];
+
+/// Life stage of `stem` in `branch` under the default (yin-reversed) school.
+pub fn life_stage(stem: HeavenlyStem, branch: EarthlyBranch) -> LifeStage {
+    life_stage_with(stem, branch, LifeStageSchool::default())
+}
+
+/// Life stage of `stem` in `branch` under `school`.
+pub fn life_stage_with(
+    stem: HeavenlyStem,
+    branch: EarthlyBranch,
+    school: LifeStageSchool,
+) -> LifeStage {
+    let (stem, forward) = match (stem.yin_yang(), school) {
+        (YinYang::Yang, _) => (stem, true),
+        (YinYang::Yin, LifeStageSchool::YinReversed) => (stem, false),
+        (YinYang::Yin, LifeStageSchool::YinYangSame) => (stem + -1, true),
+    };
+    let start = CHANG_SHENG[stem.index()].index();
+    let steps = if forward {
+        branch.index() + 12 - start
+    } else {
+        start + 12 - branch.index()
+    };
+    LifeStage::ALL[steps % 12]
+}
+
+/// Life stages of the day master (日主) in each pillar's branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartLifeStages {
+    pub day_master: HeavenlyStem,
+    pub school: LifeStageSchool,
+    pub year: LifeStage,
+    pub month: LifeStage,
+    pub day: LifeStage,
+    pub hour: LifeStage,
+}
+
+impl fmt::Display for ChartLifeStages {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "年 {} | 月 {} | 日 {} | 时 {}",
+            self.year, self.month, self.day, self.hour
+        )
+    }
+}
+
+/// Life stages of the day master across the four pillars.
+pub fn chart_life_stages(pillars: &BaziPillars, school: LifeStageSchool) -> ChartLifeStages {
+    let day_master = pillars.day.stem();
+    let stage = |branch| life_stage_with(day_master, branch, school);
+    ChartLifeStages {
+        day_master,
+        school,
+        year: stage(pillars.year.branch()),
+        month: stage(pillars.month.branch()),
+        day: stage(pillars.day.branch()),
+        hour: stage(pillars.hour.branch()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use EarthlyBranch::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn yang_stems_run_forward() {
+        assert_eq!(life_stage(HeavenlyStem::Jia, Hai), LifeStage::ChangSheng);
+        assert_eq!(life_stage(HeavenlyStem::Jia, Yin), LifeStage::LinGuan);
+        assert_eq!(life_stage(HeavenlyStem::Jia, Mao), LifeStage::DiWang);
+        assert_eq!(life_stage(HeavenlyStem::Jia, Wei), LifeStage::Mu);
+        assert_eq!(life_stage(HeavenlyStem::Geng, Chou), LifeStage::Mu);
+        assert_eq!(life_stage(HeavenlyStem::Wu, Xu), LifeStage::Mu);
+        assert_eq!(life_stage(HeavenlyStem::Ren, Zi), LifeStage::DiWang);
+    }
+
+    #[test]
+    fn yin_stems_by_school() {
+        // Reversed: 乙 is born in 午, 禄 in 卯, dies in 亥, 墓 in 戌.
+        assert_eq!(life_stage(HeavenlyStem::Yi, Wu), LifeStage::ChangSheng);
+        assert_eq!(life_stage(HeavenlyStem::Yi, Mao), LifeStage::LinGuan);
+        assert_eq!(life_stage(HeavenlyStem::Yi, Hai), LifeStage::Si);
+        assert_eq!(life_stage(HeavenlyStem::Yi, Xu), LifeStage::Mu);
+        assert_eq!(life_stage(HeavenlyStem::Gui, Zi), LifeStage::LinGuan);
+        assert_eq!(life_stage(HeavenlyStem::Ji, You), LifeStage::ChangSheng);
+
+        // Same school: 乙 follows 甲.
+        let same = LifeStageSchool::YinYangSame;
+        for branch in EarthlyBranch::ALL {
+            assert_eq!(
+                life_stage_with(HeavenlyStem::Yi, branch, same),
+                life_stage(HeavenlyStem::Jia, branch)
+            );
+        }
+        assert_eq!(life_stage_with(HeavenlyStem::Yi, Wei, same), LifeStage::Mu);
+    }
+
+    #[test]
+    fn each_stem_visits_every_stage_once() {
+        for school in [LifeStageSchool::YinReversed, LifeStageSchool::YinYangSame] {
+            for stem in HeavenlyStem::ALL {
+                let seen: HashSet<LifeStage> = EarthlyBranch::ALL
+                    .iter()
+                    .map(|&b| life_stage_with(stem, b, school))
+                    .collect();
+                assert_eq!(seen.len(), 12, "{stem}");
+            }
+        }
+    }
+
+    #[test]
+    fn day_master_across_pillars() {
+        let pillars: BaziPillars = "乙丑 庚辰 庚寅 庚辰".parse().unwrap();
+        let stages = chart_life_stages(&pillars, LifeStageSchool::default());
+        assert_eq!(stages.to_string(), "年 墓 | 月 养 | 日 绝 | 时 养");
+    }
+}
@@ -0,0 +1,218 @@
+use crate::bazi_calc::{BaziOptions, surrounding_jie, to_table_time, try_get_pillars_with};
+use crate::error::LunarError;
+use crate::ganzhi::{GanZhi, HeavenlyStem, YinYang};
+use chrono::{DateTime, Months, NaiveDate, TimeZone};
+use std::fmt;
+
+/// Number of decade pillars returned by [`luck_pillars`].
+pub const LUCK_PILLAR_COUNT: usize = 10;
+
+/// Seconds of Jie distance per month of start age: 3 days = 1 year, so 6 hours = 1 month.
+const SECONDS_PER_START_MONTH: i64 = 6 * 3600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+/// Order in which the luck pillars follow the month pillar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LuckDirection {
+    /// 顺排: counted forward to the next Jie.
+    Forward,
+    /// 逆排: counted back to the previous Jie.
+    Backward,
+}
+
+impl LuckDirection {
+    /// Yang-year males and yin-year females run forward, the others backward.
+    pub fn for_chart(year_stem: HeavenlyStem, gender: Gender) -> LuckDirection {
+        match (year_stem.yin_yang(), gender) {
+            (YinYang::Yang, Gender::Male) | (YinYang::Yin, Gender::Female) => {
+                LuckDirection::Forward
+            }
+            _ => LuckDirection::Backward,
+        }
+    }
+}
+
+/// Elapsed time from birth, in whole years and months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuckAge {
+    pub years: u32,
+    pub months: u32,
+}
+
+impl LuckAge {
+    fn from_months(total: u32) -> LuckAge {
+        LuckAge {
+            years: total / 12,
+            months: total % 12,
+        }
+    }
+
+    pub fn total_months(self) -> u32 {
+        self.years * 12 + self.months
+    }
+}
+
+impl fmt::Display for LuckAge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}岁{}个月", self.years, self.months)
+    }
+}
+
+/// One decade luck pillar (大运).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuckPillar {
+    pub pillar: GanZhi,
+    pub start_age: LuckAge,
+    /// Gregorian date, in the birth's civil calendar, on which the pillar begins.
+    pub start_date: NaiveDate,
+}
+
+impl fmt::Display for LuckPillar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (起 {}, {})",
+            self.pillar, self.start_age, self.start_date
+        )
+    }
+}
+
+/// Decade luck pillars for a birth, starting from the month pillar.
+///
+/// Returns an empty list when the birth is outside the table.
+pub fn luck_pillars<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    gender: Gender,
+    options: &BaziOptions,
+) -> Vec<LuckPillar> {
+    try_luck_pillars(dt, gender, options).unwrap_or_default()
+}
+
+/// Like [`luck_pillars`], but explains why the list is unavailable.
+///
+/// The start age (起运) is the distance from birth to the next Jie (forward) or
+/// back to the previous Jie (backward), at 3 days per year, truncated to the month.
+pub fn try_luck_pillars<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    gender: Gender,
+    options: &BaziOptions,
+) -> Result<Vec<LuckPillar>, LunarError> {
+    let pillars = try_get_pillars_with(dt.clone(), options)?;
+    let direction = LuckDirection::for_chart(pillars.year.stem(), gender);
+
+    let birth = to_table_time(&dt);
+    let no_term = LunarError::NoSurroundingTerm { time: birth };
+    let (prev, next) = surrounding_jie(birth)?;
+    let distance = match direction {
+        LuckDirection::Forward => next.ok_or(no_term)?.1 - birth,
+        LuckDirection::Backward => birth - prev.ok_or(no_term)?.1,
+    };
+    let start_months = (distance.num_seconds() / SECONDS_PER_START_MONTH) as u32;
+    let birth_date = dt.naive_local().date();
+
+    let step = match direction {
+        LuckDirection::Forward => 1,
+        LuckDirection::Backward => -1,
+    };
+    Ok((0..LUCK_PILLAR_COUNT as u32)
+        .filter_map(|i| {
+            let months = start_months + 120 * i;
+            Some(LuckPillar {
+                pillar: pillars.month + step * (i as i32 + 1),
+                start_age: LuckAge::from_months(months),
+                start_date: birth_date.checked_add_months(Months::new(months))?,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn beijing(s: &str) -> DateTime<FixedOffset> {
+        let dt = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        FixedOffset::east_opt(8 * 3600)
+            .unwrap()
+            .from_local_datetime(&dt)
+            .unwrap()
+    }
+
+    #[test]
+    fn direction_from_year_polarity_and_gender() {
+        assert_eq!(
+            LuckDirection::for_chart(HeavenlyStem::Jia, Gender::Male),
+            LuckDirection::Forward
+        );
+        assert_eq!(
+            LuckDirection::for_chart(HeavenlyStem::Yi, Gender::Male),
+            LuckDirection::Backward
+        );
+        assert_eq!(
+            LuckDirection::for_chart(HeavenlyStem::Yi, Gender::Female),
+            LuckDirection::Forward
+        );
+    }
+
+    #[test]
+    fn backward_luck_from_previous_jie() {
+        // 乙丑 year male runs backward from 庚辰 month. 清明 was 1985-04-05 04:13:35,
+        // 16d 3h 46m before birth: 64 whole six-hour units = 5 years 4 months.
+        let luck = luck_pillars(
+            beijing("1985-04-21 08:00:00"),
+            Gender::Male,
+            &BaziOptions::default(),
+        );
+        assert_eq!(luck.len(), LUCK_PILLAR_COUNT);
+        let names: Vec<String> = luck.iter().take(8).map(|l| l.pillar.to_string()).collect();
+        assert_eq!(
+            names,
+            [
+                "己卯", "戊寅", "丁丑", "丙子", "乙亥", "甲戌", "癸酉", "壬申"
+            ]
+        );
+        assert_eq!(luck[0].start_age.to_string(), "5岁4个月");
+        assert_eq!(
+            luck[0].start_date,
+            NaiveDate::from_ymd_opt(1990, 8, 21).unwrap()
+        );
+        assert_eq!(luck[1].start_age.to_string(), "15岁4个月");
+        assert_eq!(
+            luck[1].start_date,
+            NaiveDate::from_ymd_opt(2000, 8, 21).unwrap()
+        );
+    }
+
+    #[test]
+    fn forward_luck_to_next_jie() {
+        // Female with a yin year runs forward: 立夏 is 14d 13h 42m ahead, 58 units.
+        let luck = luck_pillars(
+            beijing("1985-04-21 08:00:00"),
+            Gender::Female,
+            &BaziOptions::default(),
+        );
+        assert_eq!(luck[0].pillar, "辛巳");
+        assert_eq!(luck[7].pillar, "戊子");
+        assert_eq!(luck[0].start_age.to_string(), "4岁10个月");
+    }
+
+    #[test]
+    fn out_of_range_birth() {
+        let dt = beijing("1899-06-01 00:00:00");
+        assert!(luck_pillars(dt, Gender::Male, &BaziOptions::default()).is_empty());
+        assert!(matches!(
+            try_luck_pillars(dt, Gender::Male, &BaziOptions::default()),
+            Err(LunarError::OutOfRange { .. })
+        ));
+    }
+}
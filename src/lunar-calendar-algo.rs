@@ -1,11 +1,81 @@
 #[path = "lunar-calendar-data-1900-2099.rs"]
 pub mod data;
 
+mod almanac;
 mod bazi_calc;
+mod date_search;
+mod element_strength;
+mod error;
+mod festivals;
+mod flow;
+mod ganzhi;
+mod hidden_stems;
+mod interactions;
+mod life_stage;
+mod luck_pillars;
+mod lunar_date;
+mod periods;
+mod rpc;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shen_sha;
+mod solar_time;
 mod table_lookup;
+mod ten_gods;
+mod xun;
 
-pub use bazi_calc::{BaziPillars, JieQiContext, get_jieqi_context, get_pillars};
+pub use almanac::{
+    ALMANAC_RULES, Activity, AlmanacCondition, AlmanacDay, AlmanacRule, DayClash, DayOfficer,
+    Direction, Mansion, almanac, try_almanac, try_almanac_with,
+};
+pub use bazi_calc::{
+    BaziOptions, BaziPillars, JieQiContext, TABLE_UTC_OFFSET_SECONDS, ZiHourRule,
+    get_jieqi_context, get_jieqi_context_tz, get_pillars, get_pillars_solar, get_pillars_tz,
+    get_pillars_with, to_table_time, try_get_jieqi_context, try_get_jieqi_context_tz,
+    try_get_pillars, try_get_pillars_solar, try_get_pillars_tz, try_get_pillars_with,
+};
+pub use date_search::{
+    DateCandidate, DateConstraint, DateSearch, PREFERRED_ACTIVITY_SCORE, search_dates,
+    try_search_dates,
+};
+pub use element_strength::{
+    DayMasterStrength, ElementStrength, SeasonalState, StrengthWeights, element_strength,
+    element_strength_with,
+};
+pub use error::LunarError;
+pub use festivals::{
+    Festival, FestivalDay, FestivalKind, NineOrdinal, festival_on, festivals_in, try_festivals_in,
+};
+pub use flow::{FlowMonth, FlowYear, flow_months, flow_years, try_flow_months, try_flow_year};
+pub use ganzhi::{EarthlyBranch, Element, GanZhi, HeavenlyStem, ParseGanZhiError, YinYang};
+pub use hidden_stems::{
+    HiddenQi, HiddenStem, HiddenStemWeights, hidden_stems, weighted_hidden_stems,
+};
+pub use interactions::{
+    Interaction, InteractionKind, PillarPosition, PunishmentKind, interactions, interactions_with,
+};
+pub use life_stage::{
+    ChartLifeStages, LifeStage, LifeStageSchool, chart_life_stages, life_stage, life_stage_with,
+};
+pub use luck_pillars::{
+    Gender, LUCK_PILLAR_COUNT, LuckAge, LuckDirection, LuckPillar, luck_pillars, try_luck_pillars,
+};
+pub use lunar_date::{
+    LunarDate, from_lunar_date, lunar_month_days, to_lunar_date, try_from_lunar_date,
+    try_to_lunar_date,
+};
+pub use periods::{DatePeriod, SanFu, ShuJiu, sanfu, shujiu, try_sanfu, try_shujiu};
+pub use rpc::{
+    RPC_METHODS, RpcError, handle_rpc_line, handle_rpc_line_with, jieqi_context_json,
+    lunar_date_json, pillars_json, rpc_call, serve, serve_with, table_rfc3339, year_data_json,
+};
+pub use shen_sha::{
+    STAR_RULES, Star, StarHit, StarKey, StarRule, StarTargets, shen_sha, shen_sha_with,
+};
+pub use solar_time::{SolarTimeCorrection, equation_of_time_minutes};
 pub use table_lookup::{
     MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR, get_cycle_index, get_nayin_by_index, get_nayin_by_year,
-    get_stem_branch, get_year_data,
+    get_stem_branch, get_year_data, try_get_year_data,
 };
+pub use ten_gods::{ChartTenGods, PillarTenGods, TenGod, ten_gods};
+pub use xun::{XunVoid, chart_voids};
@@ -9,16 +9,35 @@ pub struct JieQiData {
 #[derive(Debug)]
 pub struct BaziYearData {
     pub year: i32,
-    pub lunar_new_year: &'static str, // 农历正月初一
-    pub lichun_time: &'static str,    // 立春交接时间
+    pub lunar_new_year: &'static str,       // 农历正月初一
+    pub lichun_time: &'static str,          // 立春交接时间
+    pub leap_month: u8,                     // 闰月月份 (0 = 无闰月)
+    pub new_moons: &'static [&'static str], // 各月初一 (含闰月, 末项为次年正月初一)
     pub jieqi: [JieQiData; 24],
 }
 
-pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
+pub static BAZI_YEAR_DATA: [BaziYearData; 200] = [
     BaziYearData {
         year: 1900,
         lunar_new_year: "1900-01-31",
         lichun_time: "1900-02-04 13:51:31",
+        leap_month: 8,
+        new_moons: &[
+            "1900-01-31",
+            "1900-03-01",
+            "1900-03-31",
+            "1900-04-29",
+            "1900-05-28",
+            "1900-06-27",
+            "1900-07-26",
+            "1900-08-25",
+            "1900-09-24",
+            "1900-10-23",
+            "1900-11-22",
+            "1900-12-22",
+            "1901-01-20",
+            "1901-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -122,6 +141,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1901,
         lunar_new_year: "1901-02-19",
         lichun_time: "1901-02-04 19:39:52",
+        leap_month: 0,
+        new_moons: &[
+            "1901-02-19",
+            "1901-03-20",
+            "1901-04-19",
+            "1901-05-18",
+            "1901-06-16",
+            "1901-07-16",
+            "1901-08-14",
+            "1901-09-13",
+            "1901-10-12",
+            "1901-11-11",
+            "1901-12-11",
+            "1902-01-10",
+            "1902-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -225,6 +260,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1902,
         lunar_new_year: "1902-02-08",
         lichun_time: "1902-02-05 01:38:10",
+        leap_month: 0,
+        new_moons: &[
+            "1902-02-08",
+            "1902-03-10",
+            "1902-04-08",
+            "1902-05-08",
+            "1902-06-06",
+            "1902-07-05",
+            "1902-08-04",
+            "1902-09-02",
+            "1902-10-02",
+            "1902-10-31",
+            "1902-11-30",
+            "1902-12-30",
+            "1903-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -328,6 +379,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1903,
         lunar_new_year: "1903-01-29",
         lichun_time: "1903-02-05 07:31:17",
+        leap_month: 5,
+        new_moons: &[
+            "1903-01-29",
+            "1903-02-27",
+            "1903-03-29",
+            "1903-04-27",
+            "1903-05-27",
+            "1903-06-25",
+            "1903-07-24",
+            "1903-08-23",
+            "1903-09-21",
+            "1903-10-20",
+            "1903-11-19",
+            "1903-12-19",
+            "1904-01-17",
+            "1904-02-16",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -431,6 +499,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1904,
         lunar_new_year: "1904-02-16",
         lichun_time: "1904-02-05 13:24:07",
+        leap_month: 0,
+        new_moons: &[
+            "1904-02-16",
+            "1904-03-17",
+            "1904-04-16",
+            "1904-05-15",
+            "1904-06-14",
+            "1904-07-13",
+            "1904-08-11",
+            "1904-09-10",
+            "1904-10-09",
+            "1904-11-07",
+            "1904-12-07",
+            "1905-01-06",
+            "1905-02-04",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -534,6 +618,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1905,
         lunar_new_year: "1905-02-04",
         lichun_time: "1905-02-04 19:15:49",
+        leap_month: 0,
+        new_moons: &[
+            "1905-02-04",
+            "1905-03-06",
+            "1905-04-05",
+            "1905-05-04",
+            "1905-06-03",
+            "1905-07-03",
+            "1905-08-01",
+            "1905-08-30",
+            "1905-09-29",
+            "1905-10-28",
+            "1905-11-27",
+            "1905-12-26",
+            "1906-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -637,6 +737,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1906,
         lunar_new_year: "1906-01-25",
         lichun_time: "1906-02-05 01:03:54",
+        leap_month: 4,
+        new_moons: &[
+            "1906-01-25",
+            "1906-02-23",
+            "1906-03-25",
+            "1906-04-24",
+            "1906-05-23",
+            "1906-06-22",
+            "1906-07-21",
+            "1906-08-20",
+            "1906-09-18",
+            "1906-10-18",
+            "1906-11-16",
+            "1906-12-16",
+            "1907-01-14",
+            "1907-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -740,6 +857,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1907,
         lunar_new_year: "1907-02-13",
         lichun_time: "1907-02-05 06:58:49",
+        leap_month: 0,
+        new_moons: &[
+            "1907-02-13",
+            "1907-03-14",
+            "1907-04-13",
+            "1907-05-12",
+            "1907-06-11",
+            "1907-07-10",
+            "1907-08-09",
+            "1907-09-08",
+            "1907-10-07",
+            "1907-11-06",
+            "1907-12-05",
+            "1908-01-04",
+            "1908-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -843,6 +976,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1908,
         lunar_new_year: "1908-02-02",
         lichun_time: "1908-02-05 12:47:13",
+        leap_month: 0,
+        new_moons: &[
+            "1908-02-02",
+            "1908-03-03",
+            "1908-04-01",
+            "1908-04-30",
+            "1908-05-30",
+            "1908-06-29",
+            "1908-07-28",
+            "1908-08-27",
+            "1908-09-25",
+            "1908-10-25",
+            "1908-11-24",
+            "1908-12-23",
+            "1909-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -946,6 +1095,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1909,
         lunar_new_year: "1909-01-22",
         lichun_time: "1909-02-04 18:32:31",
+        leap_month: 2,
+        new_moons: &[
+            "1909-01-22",
+            "1909-02-20",
+            "1909-03-22",
+            "1909-04-20",
+            "1909-05-19",
+            "1909-06-18",
+            "1909-07-17",
+            "1909-08-16",
+            "1909-09-14",
+            "1909-10-14",
+            "1909-11-13",
+            "1909-12-13",
+            "1910-01-11",
+            "1910-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1049,6 +1215,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1910,
         lunar_new_year: "1910-02-10",
         lichun_time: "1910-02-05 00:27:22",
+        leap_month: 0,
+        new_moons: &[
+            "1910-02-10",
+            "1910-03-11",
+            "1910-04-10",
+            "1910-05-09",
+            "1910-06-07",
+            "1910-07-07",
+            "1910-08-05",
+            "1910-09-04",
+            "1910-10-03",
+            "1910-11-02",
+            "1910-12-02",
+            "1911-01-01",
+            "1911-01-30",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1152,6 +1334,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1911,
         lunar_new_year: "1911-01-30",
         lichun_time: "1911-02-05 06:10:16",
+        leap_month: 6,
+        new_moons: &[
+            "1911-01-30",
+            "1911-03-01",
+            "1911-03-30",
+            "1911-04-29",
+            "1911-05-28",
+            "1911-06-26",
+            "1911-07-26",
+            "1911-08-24",
+            "1911-09-22",
+            "1911-10-22",
+            "1911-11-21",
+            "1911-12-20",
+            "1912-01-19",
+            "1912-02-18",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1255,6 +1454,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1912,
         lunar_new_year: "1912-02-18",
         lichun_time: "1912-02-05 11:53:31",
+        leap_month: 0,
+        new_moons: &[
+            "1912-02-18",
+            "1912-03-19",
+            "1912-04-17",
+            "1912-05-17",
+            "1912-06-15",
+            "1912-07-14",
+            "1912-08-13",
+            "1912-09-11",
+            "1912-10-10",
+            "1912-11-09",
+            "1912-12-09",
+            "1913-01-07",
+            "1913-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1358,6 +1573,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1913,
         lunar_new_year: "1913-02-06",
         lichun_time: "1913-02-04 17:42:38",
+        leap_month: 0,
+        new_moons: &[
+            "1913-02-06",
+            "1913-03-08",
+            "1913-04-07",
+            "1913-05-06",
+            "1913-06-05",
+            "1913-07-04",
+            "1913-08-02",
+            "1913-09-01",
+            "1913-09-30",
+            "1913-10-29",
+            "1913-11-28",
+            "1913-12-27",
+            "1914-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1461,6 +1692,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1914,
         lunar_new_year: "1914-01-26",
         lichun_time: "1914-02-04 23:29:16",
+        leap_month: 5,
+        new_moons: &[
+            "1914-01-26",
+            "1914-02-25",
+            "1914-03-27",
+            "1914-04-25",
+            "1914-05-25",
+            "1914-06-23",
+            "1914-07-23",
+            "1914-08-21",
+            "1914-09-20",
+            "1914-10-19",
+            "1914-11-17",
+            "1914-12-17",
+            "1915-01-15",
+            "1915-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1564,6 +1812,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1915,
         lunar_new_year: "1915-02-14",
         lichun_time: "1915-02-05 05:25:26",
+        leap_month: 0,
+        new_moons: &[
+            "1915-02-14",
+            "1915-03-16",
+            "1915-04-14",
+            "1915-05-14",
+            "1915-06-13",
+            "1915-07-12",
+            "1915-08-11",
+            "1915-09-09",
+            "1915-10-09",
+            "1915-11-07",
+            "1915-12-07",
+            "1916-01-05",
+            "1916-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1667,6 +1931,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1916,
         lunar_new_year: "1916-02-03",
         lichun_time: "1916-02-05 11:13:58",
+        leap_month: 0,
+        new_moons: &[
+            "1916-02-03",
+            "1916-03-04",
+            "1916-04-03",
+            "1916-05-02",
+            "1916-06-01",
+            "1916-06-30",
+            "1916-07-30",
+            "1916-08-29",
+            "1916-09-27",
+            "1916-10-27",
+            "1916-11-25",
+            "1916-12-25",
+            "1917-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1770,6 +2050,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1917,
         lunar_new_year: "1917-01-23",
         lichun_time: "1917-02-04 16:57:32",
+        leap_month: 2,
+        new_moons: &[
+            "1917-01-23",
+            "1917-02-22",
+            "1917-03-23",
+            "1917-04-21",
+            "1917-05-21",
+            "1917-06-19",
+            "1917-07-19",
+            "1917-08-18",
+            "1917-09-16",
+            "1917-10-16",
+            "1917-11-15",
+            "1917-12-14",
+            "1918-01-13",
+            "1918-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1873,6 +2170,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1918,
         lunar_new_year: "1918-02-11",
         lichun_time: "1918-02-04 22:53:05",
+        leap_month: 0,
+        new_moons: &[
+            "1918-02-11",
+            "1918-03-13",
+            "1918-04-11",
+            "1918-05-10",
+            "1918-06-09",
+            "1918-07-08",
+            "1918-08-07",
+            "1918-09-05",
+            "1918-10-05",
+            "1918-11-04",
+            "1918-12-03",
+            "1919-01-02",
+            "1919-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -1976,6 +2289,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1919,
         lunar_new_year: "1919-02-01",
         lichun_time: "1919-02-05 04:39:23",
+        leap_month: 7,
+        new_moons: &[
+            "1919-02-01",
+            "1919-03-02",
+            "1919-04-01",
+            "1919-04-30",
+            "1919-05-29",
+            "1919-06-28",
+            "1919-07-27",
+            "1919-08-25",
+            "1919-09-24",
+            "1919-10-24",
+            "1919-11-22",
+            "1919-12-22",
+            "1920-01-21",
+            "1920-02-20",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2079,6 +2409,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1920,
         lunar_new_year: "1920-02-20",
         lichun_time: "1920-02-05 10:26:26",
+        leap_month: 0,
+        new_moons: &[
+            "1920-02-20",
+            "1920-03-20",
+            "1920-04-19",
+            "1920-05-18",
+            "1920-06-16",
+            "1920-07-16",
+            "1920-08-14",
+            "1920-09-12",
+            "1920-10-12",
+            "1920-11-10",
+            "1920-12-10",
+            "1921-01-09",
+            "1921-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2182,6 +2528,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1921,
         lunar_new_year: "1921-02-08",
         lichun_time: "1921-02-04 16:20:12",
+        leap_month: 0,
+        new_moons: &[
+            "1921-02-08",
+            "1921-03-10",
+            "1921-04-08",
+            "1921-05-08",
+            "1921-06-06",
+            "1921-07-05",
+            "1921-08-04",
+            "1921-09-02",
+            "1921-10-01",
+            "1921-10-31",
+            "1921-11-29",
+            "1921-12-29",
+            "1922-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2285,6 +2647,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1922,
         lunar_new_year: "1922-01-28",
         lichun_time: "1922-02-04 22:06:24",
+        leap_month: 5,
+        new_moons: &[
+            "1922-01-28",
+            "1922-02-27",
+            "1922-03-28",
+            "1922-04-27",
+            "1922-05-27",
+            "1922-06-25",
+            "1922-07-24",
+            "1922-08-23",
+            "1922-09-21",
+            "1922-10-20",
+            "1922-11-19",
+            "1922-12-18",
+            "1923-01-17",
+            "1923-02-16",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2388,6 +2767,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1923,
         lunar_new_year: "1923-02-16",
         lichun_time: "1923-02-05 04:00:17",
+        leap_month: 0,
+        new_moons: &[
+            "1923-02-16",
+            "1923-03-17",
+            "1923-04-16",
+            "1923-05-16",
+            "1923-06-14",
+            "1923-07-14",
+            "1923-08-12",
+            "1923-09-11",
+            "1923-10-10",
+            "1923-11-08",
+            "1923-12-08",
+            "1924-01-06",
+            "1924-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2491,6 +2886,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1924,
         lunar_new_year: "1924-02-05",
         lichun_time: "1924-02-05 09:49:32",
+        leap_month: 0,
+        new_moons: &[
+            "1924-02-05",
+            "1924-03-05",
+            "1924-04-04",
+            "1924-05-04",
+            "1924-06-02",
+            "1924-07-02",
+            "1924-08-01",
+            "1924-08-30",
+            "1924-09-29",
+            "1924-10-28",
+            "1924-11-27",
+            "1924-12-26",
+            "1925-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2594,6 +3005,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1925,
         lunar_new_year: "1925-01-24",
         lichun_time: "1925-02-04 15:36:45",
+        leap_month: 4,
+        new_moons: &[
+            "1925-01-24",
+            "1925-02-23",
+            "1925-03-24",
+            "1925-04-23",
+            "1925-05-22",
+            "1925-06-21",
+            "1925-07-21",
+            "1925-08-19",
+            "1925-09-18",
+            "1925-10-18",
+            "1925-11-16",
+            "1925-12-16",
+            "1926-01-14",
+            "1926-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2697,6 +3125,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1926,
         lunar_new_year: "1926-02-13",
         lichun_time: "1926-02-04 21:38:16",
+        leap_month: 0,
+        new_moons: &[
+            "1926-02-13",
+            "1926-03-14",
+            "1926-04-12",
+            "1926-05-12",
+            "1926-06-10",
+            "1926-07-10",
+            "1926-08-08",
+            "1926-09-07",
+            "1926-10-07",
+            "1926-11-05",
+            "1926-12-05",
+            "1927-01-04",
+            "1927-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2800,6 +3244,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1927,
         lunar_new_year: "1927-02-02",
         lichun_time: "1927-02-05 03:30:02",
+        leap_month: 0,
+        new_moons: &[
+            "1927-02-02",
+            "1927-03-04",
+            "1927-04-02",
+            "1927-05-01",
+            "1927-05-31",
+            "1927-06-29",
+            "1927-07-29",
+            "1927-08-27",
+            "1927-09-26",
+            "1927-10-25",
+            "1927-11-24",
+            "1927-12-24",
+            "1928-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -2903,6 +3363,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1928,
         lunar_new_year: "1928-01-23",
         lichun_time: "1928-02-05 09:16:22",
+        leap_month: 2,
+        new_moons: &[
+            "1928-01-23",
+            "1928-02-21",
+            "1928-03-22",
+            "1928-04-20",
+            "1928-05-19",
+            "1928-06-18",
+            "1928-07-17",
+            "1928-08-15",
+            "1928-09-14",
+            "1928-10-13",
+            "1928-11-12",
+            "1928-12-12",
+            "1929-01-11",
+            "1929-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3006,6 +3483,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1929,
         lunar_new_year: "1929-02-10",
         lichun_time: "1929-02-04 15:08:43",
+        leap_month: 0,
+        new_moons: &[
+            "1929-02-10",
+            "1929-03-11",
+            "1929-04-10",
+            "1929-05-09",
+            "1929-06-07",
+            "1929-07-07",
+            "1929-08-05",
+            "1929-09-03",
+            "1929-10-03",
+            "1929-11-01",
+            "1929-12-01",
+            "1929-12-31",
+            "1930-01-30",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3109,6 +3602,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1930,
         lunar_new_year: "1930-01-30",
         lichun_time: "1930-02-04 20:51:07",
+        leap_month: 6,
+        new_moons: &[
+            "1930-01-30",
+            "1930-02-28",
+            "1930-03-30",
+            "1930-04-29",
+            "1930-05-28",
+            "1930-06-26",
+            "1930-07-26",
+            "1930-08-24",
+            "1930-09-22",
+            "1930-10-22",
+            "1930-11-20",
+            "1930-12-20",
+            "1931-01-19",
+            "1931-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3212,6 +3722,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1931,
         lunar_new_year: "1931-02-17",
         lichun_time: "1931-02-05 02:40:38",
+        leap_month: 0,
+        new_moons: &[
+            "1931-02-17",
+            "1931-03-19",
+            "1931-04-18",
+            "1931-05-17",
+            "1931-06-16",
+            "1931-07-15",
+            "1931-08-14",
+            "1931-09-12",
+            "1931-10-11",
+            "1931-11-10",
+            "1931-12-09",
+            "1932-01-08",
+            "1932-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3315,6 +3841,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1932,
         lunar_new_year: "1932-02-06",
         lichun_time: "1932-02-05 08:29:20",
+        leap_month: 0,
+        new_moons: &[
+            "1932-02-06",
+            "1932-03-07",
+            "1932-04-06",
+            "1932-05-06",
+            "1932-06-04",
+            "1932-07-04",
+            "1932-08-02",
+            "1932-09-01",
+            "1932-09-30",
+            "1932-10-29",
+            "1932-11-28",
+            "1932-12-27",
+            "1933-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3418,6 +3960,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1933,
         lunar_new_year: "1933-01-26",
         lichun_time: "1933-02-04 14:09:16",
+        leap_month: 5,
+        new_moons: &[
+            "1933-01-26",
+            "1933-02-24",
+            "1933-03-26",
+            "1933-04-25",
+            "1933-05-24",
+            "1933-06-23",
+            "1933-07-23",
+            "1933-08-21",
+            "1933-09-20",
+            "1933-10-19",
+            "1933-11-18",
+            "1933-12-17",
+            "1934-01-15",
+            "1934-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3521,6 +4080,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1934,
         lunar_new_year: "1934-02-14",
         lichun_time: "1934-02-04 20:03:37",
+        leap_month: 0,
+        new_moons: &[
+            "1934-02-14",
+            "1934-03-15",
+            "1934-04-14",
+            "1934-05-13",
+            "1934-06-12",
+            "1934-07-12",
+            "1934-08-10",
+            "1934-09-09",
+            "1934-10-08",
+            "1934-11-07",
+            "1934-12-07",
+            "1935-01-05",
+            "1935-02-04",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3624,6 +4199,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1935,
         lunar_new_year: "1935-02-04",
         lichun_time: "1935-02-05 01:48:41",
+        leap_month: 0,
+        new_moons: &[
+            "1935-02-04",
+            "1935-03-05",
+            "1935-04-03",
+            "1935-05-03",
+            "1935-06-01",
+            "1935-07-01",
+            "1935-07-30",
+            "1935-08-29",
+            "1935-09-28",
+            "1935-10-27",
+            "1935-11-26",
+            "1935-12-26",
+            "1936-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3727,6 +4318,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1936,
         lunar_new_year: "1936-01-24",
         lichun_time: "1936-02-05 07:29:16",
+        leap_month: 3,
+        new_moons: &[
+            "1936-01-24",
+            "1936-02-23",
+            "1936-03-23",
+            "1936-04-21",
+            "1936-05-21",
+            "1936-06-19",
+            "1936-07-18",
+            "1936-08-17",
+            "1936-09-16",
+            "1936-10-15",
+            "1936-11-14",
+            "1936-12-14",
+            "1937-01-13",
+            "1937-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3830,6 +4438,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1937,
         lunar_new_year: "1937-02-11",
         lichun_time: "1937-02-04 13:25:33",
+        leap_month: 0,
+        new_moons: &[
+            "1937-02-11",
+            "1937-03-13",
+            "1937-04-11",
+            "1937-05-10",
+            "1937-06-09",
+            "1937-07-08",
+            "1937-08-06",
+            "1937-09-05",
+            "1937-10-04",
+            "1937-11-03",
+            "1937-12-03",
+            "1938-01-02",
+            "1938-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -3933,6 +4557,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1938,
         lunar_new_year: "1938-01-31",
         lichun_time: "1938-02-04 19:14:58",
+        leap_month: 7,
+        new_moons: &[
+            "1938-01-31",
+            "1938-03-02",
+            "1938-04-01",
+            "1938-04-30",
+            "1938-05-29",
+            "1938-06-28",
+            "1938-07-27",
+            "1938-08-25",
+            "1938-09-24",
+            "1938-10-23",
+            "1938-11-22",
+            "1938-12-22",
+            "1939-01-20",
+            "1939-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4036,6 +4677,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1939,
         lunar_new_year: "1939-02-19",
         lichun_time: "1939-02-05 01:10:26",
+        leap_month: 0,
+        new_moons: &[
+            "1939-02-19",
+            "1939-03-21",
+            "1939-04-20",
+            "1939-05-19",
+            "1939-06-17",
+            "1939-07-17",
+            "1939-08-15",
+            "1939-09-13",
+            "1939-10-13",
+            "1939-11-11",
+            "1939-12-11",
+            "1940-01-09",
+            "1940-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4139,6 +4796,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1940,
         lunar_new_year: "1940-02-08",
         lichun_time: "1940-02-05 07:07:32",
+        leap_month: 0,
+        new_moons: &[
+            "1940-02-08",
+            "1940-03-09",
+            "1940-04-08",
+            "1940-05-07",
+            "1940-06-06",
+            "1940-07-05",
+            "1940-08-04",
+            "1940-09-02",
+            "1940-10-01",
+            "1940-10-31",
+            "1940-11-29",
+            "1940-12-29",
+            "1941-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4242,6 +4915,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1941,
         lunar_new_year: "1941-01-27",
         lichun_time: "1941-02-04 12:49:44",
+        leap_month: 6,
+        new_moons: &[
+            "1941-01-27",
+            "1941-02-26",
+            "1941-03-28",
+            "1941-04-26",
+            "1941-05-26",
+            "1941-06-25",
+            "1941-07-24",
+            "1941-08-23",
+            "1941-09-21",
+            "1941-10-20",
+            "1941-11-19",
+            "1941-12-18",
+            "1942-01-17",
+            "1942-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4345,6 +5035,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1942,
         lunar_new_year: "1942-02-15",
         lichun_time: "1942-02-04 18:48:34",
+        leap_month: 0,
+        new_moons: &[
+            "1942-02-15",
+            "1942-03-17",
+            "1942-04-15",
+            "1942-05-15",
+            "1942-06-14",
+            "1942-07-13",
+            "1942-08-12",
+            "1942-09-10",
+            "1942-10-10",
+            "1942-11-08",
+            "1942-12-08",
+            "1943-01-06",
+            "1943-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4448,6 +5154,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1943,
         lunar_new_year: "1943-02-05",
         lichun_time: "1943-02-05 00:40:04",
+        leap_month: 0,
+        new_moons: &[
+            "1943-02-05",
+            "1943-03-06",
+            "1943-04-05",
+            "1943-05-04",
+            "1943-06-03",
+            "1943-07-02",
+            "1943-08-01",
+            "1943-08-31",
+            "1943-09-29",
+            "1943-10-29",
+            "1943-11-27",
+            "1943-12-27",
+            "1944-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4551,6 +5273,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1944,
         lunar_new_year: "1944-01-25",
         lichun_time: "1944-02-05 06:22:55",
+        leap_month: 4,
+        new_moons: &[
+            "1944-01-25",
+            "1944-02-24",
+            "1944-03-24",
+            "1944-04-23",
+            "1944-05-22",
+            "1944-06-21",
+            "1944-07-20",
+            "1944-08-19",
+            "1944-09-17",
+            "1944-10-17",
+            "1944-11-16",
+            "1944-12-15",
+            "1945-01-14",
+            "1945-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4654,6 +5393,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1945,
         lunar_new_year: "1945-02-13",
         lichun_time: "1945-02-04 12:19:22",
+        leap_month: 0,
+        new_moons: &[
+            "1945-02-13",
+            "1945-03-14",
+            "1945-04-12",
+            "1945-05-12",
+            "1945-06-10",
+            "1945-07-09",
+            "1945-08-08",
+            "1945-09-06",
+            "1945-10-06",
+            "1945-11-05",
+            "1945-12-05",
+            "1946-01-03",
+            "1946-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4757,6 +5512,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1946,
         lunar_new_year: "1946-02-02",
         lichun_time: "1946-02-04 18:03:53",
+        leap_month: 0,
+        new_moons: &[
+            "1946-02-02",
+            "1946-03-04",
+            "1946-04-02",
+            "1946-05-01",
+            "1946-05-31",
+            "1946-06-29",
+            "1946-07-28",
+            "1946-08-27",
+            "1946-09-25",
+            "1946-10-25",
+            "1946-11-24",
+            "1946-12-23",
+            "1947-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4860,6 +5631,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1947,
         lunar_new_year: "1947-01-22",
         lichun_time: "1947-02-04 23:50:21",
+        leap_month: 2,
+        new_moons: &[
+            "1947-01-22",
+            "1947-02-21",
+            "1947-03-23",
+            "1947-04-21",
+            "1947-05-20",
+            "1947-06-19",
+            "1947-07-18",
+            "1947-08-16",
+            "1947-09-15",
+            "1947-10-14",
+            "1947-11-13",
+            "1947-12-12",
+            "1948-01-11",
+            "1948-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -4963,6 +5751,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1948,
         lunar_new_year: "1948-02-10",
         lichun_time: "1948-02-05 05:42:00",
+        leap_month: 0,
+        new_moons: &[
+            "1948-02-10",
+            "1948-03-11",
+            "1948-04-09",
+            "1948-05-09",
+            "1948-06-07",
+            "1948-07-07",
+            "1948-08-05",
+            "1948-09-03",
+            "1948-10-03",
+            "1948-11-01",
+            "1948-12-01",
+            "1948-12-30",
+            "1949-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5066,6 +5870,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1949,
         lunar_new_year: "1949-01-29",
         lichun_time: "1949-02-04 11:22:49",
+        leap_month: 7,
+        new_moons: &[
+            "1949-01-29",
+            "1949-02-28",
+            "1949-03-29",
+            "1949-04-28",
+            "1949-05-28",
+            "1949-06-26",
+            "1949-07-26",
+            "1949-08-24",
+            "1949-09-22",
+            "1949-10-22",
+            "1949-11-20",
+            "1949-12-20",
+            "1950-01-18",
+            "1950-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5169,6 +5990,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1950,
         lunar_new_year: "1950-02-17",
         lichun_time: "1950-02-04 17:20:46",
+        leap_month: 0,
+        new_moons: &[
+            "1950-02-17",
+            "1950-03-18",
+            "1950-04-17",
+            "1950-05-17",
+            "1950-06-15",
+            "1950-07-15",
+            "1950-08-14",
+            "1950-09-12",
+            "1950-10-11",
+            "1950-11-10",
+            "1950-12-09",
+            "1951-01-08",
+            "1951-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5272,6 +6109,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1951,
         lunar_new_year: "1951-02-06",
         lichun_time: "1951-02-04 23:13:26",
+        leap_month: 0,
+        new_moons: &[
+            "1951-02-06",
+            "1951-03-08",
+            "1951-04-06",
+            "1951-05-06",
+            "1951-06-05",
+            "1951-07-04",
+            "1951-08-03",
+            "1951-09-01",
+            "1951-10-01",
+            "1951-10-30",
+            "1951-11-29",
+            "1951-12-28",
+            "1952-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5375,6 +6228,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1952,
         lunar_new_year: "1952-01-27",
         lichun_time: "1952-02-05 04:52:54",
+        leap_month: 5,
+        new_moons: &[
+            "1952-01-27",
+            "1952-02-25",
+            "1952-03-26",
+            "1952-04-24",
+            "1952-05-24",
+            "1952-06-22",
+            "1952-07-22",
+            "1952-08-20",
+            "1952-09-19",
+            "1952-10-19",
+            "1952-11-17",
+            "1952-12-17",
+            "1953-01-15",
+            "1953-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5478,6 +6348,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1953,
         lunar_new_year: "1953-02-14",
         lichun_time: "1953-02-04 10:45:53",
+        leap_month: 0,
+        new_moons: &[
+            "1953-02-14",
+            "1953-03-15",
+            "1953-04-14",
+            "1953-05-13",
+            "1953-06-11",
+            "1953-07-11",
+            "1953-08-10",
+            "1953-09-08",
+            "1953-10-08",
+            "1953-11-07",
+            "1953-12-06",
+            "1954-01-05",
+            "1954-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5581,6 +6467,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1954,
         lunar_new_year: "1954-02-03",
         lichun_time: "1954-02-04 16:30:41",
+        leap_month: 0,
+        new_moons: &[
+            "1954-02-03",
+            "1954-03-05",
+            "1954-04-03",
+            "1954-05-03",
+            "1954-06-01",
+            "1954-06-30",
+            "1954-07-30",
+            "1954-08-28",
+            "1954-09-27",
+            "1954-10-27",
+            "1954-11-25",
+            "1954-12-25",
+            "1955-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5684,6 +6586,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1955,
         lunar_new_year: "1955-01-24",
         lichun_time: "1955-02-04 22:17:36",
+        leap_month: 3,
+        new_moons: &[
+            "1955-01-24",
+            "1955-02-22",
+            "1955-03-24",
+            "1955-04-22",
+            "1955-05-22",
+            "1955-06-20",
+            "1955-07-19",
+            "1955-08-18",
+            "1955-09-16",
+            "1955-10-16",
+            "1955-11-14",
+            "1955-12-14",
+            "1956-01-13",
+            "1956-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5787,6 +6706,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1956,
         lunar_new_year: "1956-02-12",
         lichun_time: "1956-02-05 04:11:55",
+        leap_month: 0,
+        new_moons: &[
+            "1956-02-12",
+            "1956-03-12",
+            "1956-04-11",
+            "1956-05-10",
+            "1956-06-09",
+            "1956-07-08",
+            "1956-08-06",
+            "1956-09-05",
+            "1956-10-04",
+            "1956-11-03",
+            "1956-12-02",
+            "1957-01-01",
+            "1957-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5890,6 +6825,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1957,
         lunar_new_year: "1957-01-31",
         lichun_time: "1957-02-04 09:54:37",
+        leap_month: 8,
+        new_moons: &[
+            "1957-01-31",
+            "1957-03-02",
+            "1957-03-31",
+            "1957-04-30",
+            "1957-05-29",
+            "1957-06-28",
+            "1957-07-27",
+            "1957-08-25",
+            "1957-09-24",
+            "1957-10-23",
+            "1957-11-22",
+            "1957-12-21",
+            "1958-01-20",
+            "1958-02-18",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -5993,6 +6945,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1958,
         lunar_new_year: "1958-02-18",
         lichun_time: "1958-02-04 15:49:11",
+        leap_month: 0,
+        new_moons: &[
+            "1958-02-18",
+            "1958-03-20",
+            "1958-04-19",
+            "1958-05-19",
+            "1958-06-17",
+            "1958-07-17",
+            "1958-08-15",
+            "1958-09-13",
+            "1958-10-13",
+            "1958-11-11",
+            "1958-12-11",
+            "1959-01-09",
+            "1959-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6096,6 +7064,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1959,
         lunar_new_year: "1959-02-08",
         lichun_time: "1959-02-04 21:42:10",
+        leap_month: 0,
+        new_moons: &[
+            "1959-02-08",
+            "1959-03-09",
+            "1959-04-08",
+            "1959-05-08",
+            "1959-06-06",
+            "1959-07-06",
+            "1959-08-04",
+            "1959-09-03",
+            "1959-10-02",
+            "1959-11-01",
+            "1959-11-30",
+            "1959-12-30",
+            "1960-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6199,6 +7183,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1960,
         lunar_new_year: "1960-01-28",
         lichun_time: "1960-02-05 03:23:09",
+        leap_month: 6,
+        new_moons: &[
+            "1960-01-28",
+            "1960-02-27",
+            "1960-03-27",
+            "1960-04-26",
+            "1960-05-25",
+            "1960-06-24",
+            "1960-07-24",
+            "1960-08-22",
+            "1960-09-21",
+            "1960-10-20",
+            "1960-11-19",
+            "1960-12-18",
+            "1961-01-17",
+            "1961-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6302,6 +7303,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1961,
         lunar_new_year: "1961-02-15",
         lichun_time: "1961-02-04 09:22:26",
+        leap_month: 0,
+        new_moons: &[
+            "1961-02-15",
+            "1961-03-17",
+            "1961-04-15",
+            "1961-05-15",
+            "1961-06-13",
+            "1961-07-13",
+            "1961-08-11",
+            "1961-09-10",
+            "1961-10-10",
+            "1961-11-08",
+            "1961-12-08",
+            "1962-01-06",
+            "1962-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6405,6 +7422,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1962,
         lunar_new_year: "1962-02-05",
         lichun_time: "1962-02-04 15:17:20",
+        leap_month: 0,
+        new_moons: &[
+            "1962-02-05",
+            "1962-03-06",
+            "1962-04-05",
+            "1962-05-04",
+            "1962-06-02",
+            "1962-07-02",
+            "1962-07-31",
+            "1962-08-30",
+            "1962-09-29",
+            "1962-10-28",
+            "1962-11-27",
+            "1962-12-27",
+            "1963-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6508,6 +7541,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1963,
         lunar_new_year: "1963-01-25",
         lichun_time: "1963-02-04 21:07:44",
+        leap_month: 4,
+        new_moons: &[
+            "1963-01-25",
+            "1963-02-24",
+            "1963-03-25",
+            "1963-04-24",
+            "1963-05-23",
+            "1963-06-21",
+            "1963-07-21",
+            "1963-08-19",
+            "1963-09-18",
+            "1963-10-17",
+            "1963-11-16",
+            "1963-12-16",
+            "1964-01-15",
+            "1964-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6611,6 +7661,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1964,
         lunar_new_year: "1964-02-13",
         lichun_time: "1964-02-05 03:04:55",
+        leap_month: 0,
+        new_moons: &[
+            "1964-02-13",
+            "1964-03-14",
+            "1964-04-12",
+            "1964-05-12",
+            "1964-06-10",
+            "1964-07-09",
+            "1964-08-08",
+            "1964-09-06",
+            "1964-10-06",
+            "1964-11-04",
+            "1964-12-04",
+            "1965-01-03",
+            "1965-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6714,6 +7780,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1965,
         lunar_new_year: "1965-02-02",
         lichun_time: "1965-02-04 08:46:06",
+        leap_month: 0,
+        new_moons: &[
+            "1965-02-02",
+            "1965-03-03",
+            "1965-04-02",
+            "1965-05-01",
+            "1965-05-31",
+            "1965-06-29",
+            "1965-07-28",
+            "1965-08-27",
+            "1965-09-25",
+            "1965-10-24",
+            "1965-11-23",
+            "1965-12-23",
+            "1966-01-21",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6817,6 +7899,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1966,
         lunar_new_year: "1966-01-21",
         lichun_time: "1966-02-04 14:37:48",
+        leap_month: 3,
+        new_moons: &[
+            "1966-01-21",
+            "1966-02-20",
+            "1966-03-22",
+            "1966-04-21",
+            "1966-05-20",
+            "1966-06-19",
+            "1966-07-18",
+            "1966-08-16",
+            "1966-09-15",
+            "1966-10-14",
+            "1966-11-12",
+            "1966-12-12",
+            "1967-01-11",
+            "1967-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -6920,6 +8019,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1967,
         lunar_new_year: "1967-02-09",
         lichun_time: "1967-02-04 20:30:49",
+        leap_month: 0,
+        new_moons: &[
+            "1967-02-09",
+            "1967-03-11",
+            "1967-04-10",
+            "1967-05-09",
+            "1967-06-08",
+            "1967-07-08",
+            "1967-08-06",
+            "1967-09-04",
+            "1967-10-04",
+            "1967-11-02",
+            "1967-12-02",
+            "1967-12-31",
+            "1968-01-30",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7023,6 +8138,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1968,
         lunar_new_year: "1968-01-30",
         lichun_time: "1968-02-05 02:07:23",
+        leap_month: 7,
+        new_moons: &[
+            "1968-01-30",
+            "1968-02-28",
+            "1968-03-29",
+            "1968-04-27",
+            "1968-05-27",
+            "1968-06-26",
+            "1968-07-25",
+            "1968-08-24",
+            "1968-09-22",
+            "1968-10-22",
+            "1968-11-20",
+            "1968-12-20",
+            "1969-01-18",
+            "1969-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7126,6 +8258,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1969,
         lunar_new_year: "1969-02-17",
         lichun_time: "1969-02-04 07:58:52",
+        leap_month: 0,
+        new_moons: &[
+            "1969-02-17",
+            "1969-03-18",
+            "1969-04-17",
+            "1969-05-16",
+            "1969-06-15",
+            "1969-07-14",
+            "1969-08-13",
+            "1969-09-12",
+            "1969-10-11",
+            "1969-11-10",
+            "1969-12-09",
+            "1970-01-08",
+            "1970-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7229,6 +8377,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1970,
         lunar_new_year: "1970-02-06",
         lichun_time: "1970-02-04 13:45:42",
+        leap_month: 0,
+        new_moons: &[
+            "1970-02-06",
+            "1970-03-08",
+            "1970-04-06",
+            "1970-05-05",
+            "1970-06-04",
+            "1970-07-03",
+            "1970-08-02",
+            "1970-09-01",
+            "1970-09-30",
+            "1970-10-30",
+            "1970-11-29",
+            "1970-12-28",
+            "1971-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7332,6 +8496,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1971,
         lunar_new_year: "1971-01-27",
         lichun_time: "1971-02-04 19:25:25",
+        leap_month: 5,
+        new_moons: &[
+            "1971-01-27",
+            "1971-02-25",
+            "1971-03-27",
+            "1971-04-25",
+            "1971-05-24",
+            "1971-06-23",
+            "1971-07-22",
+            "1971-08-21",
+            "1971-09-19",
+            "1971-10-19",
+            "1971-11-18",
+            "1971-12-18",
+            "1972-01-16",
+            "1972-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7435,6 +8616,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1972,
         lunar_new_year: "1972-02-15",
         lichun_time: "1972-02-05 01:20:13",
+        leap_month: 0,
+        new_moons: &[
+            "1972-02-15",
+            "1972-03-15",
+            "1972-04-14",
+            "1972-05-13",
+            "1972-06-11",
+            "1972-07-11",
+            "1972-08-09",
+            "1972-09-08",
+            "1972-10-07",
+            "1972-11-06",
+            "1972-12-06",
+            "1973-01-04",
+            "1973-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7538,6 +8735,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1973,
         lunar_new_year: "1973-02-03",
         lichun_time: "1973-02-04 07:04:12",
+        leap_month: 0,
+        new_moons: &[
+            "1973-02-03",
+            "1973-03-05",
+            "1973-04-03",
+            "1973-05-03",
+            "1973-06-01",
+            "1973-06-30",
+            "1973-07-30",
+            "1973-08-28",
+            "1973-09-26",
+            "1973-10-26",
+            "1973-11-25",
+            "1973-12-24",
+            "1974-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7641,6 +8854,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1974,
         lunar_new_year: "1974-01-23",
         lichun_time: "1974-02-04 13:00:05",
+        leap_month: 4,
+        new_moons: &[
+            "1974-01-23",
+            "1974-02-22",
+            "1974-03-24",
+            "1974-04-22",
+            "1974-05-22",
+            "1974-06-20",
+            "1974-07-19",
+            "1974-08-18",
+            "1974-09-16",
+            "1974-10-15",
+            "1974-11-14",
+            "1974-12-14",
+            "1975-01-12",
+            "1975-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7744,6 +8974,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1975,
         lunar_new_year: "1975-02-11",
         lichun_time: "1975-02-04 18:59:12",
+        leap_month: 0,
+        new_moons: &[
+            "1975-02-11",
+            "1975-03-13",
+            "1975-04-12",
+            "1975-05-11",
+            "1975-06-10",
+            "1975-07-09",
+            "1975-08-07",
+            "1975-09-06",
+            "1975-10-05",
+            "1975-11-03",
+            "1975-12-03",
+            "1976-01-01",
+            "1976-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7847,6 +9093,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1976,
         lunar_new_year: "1976-01-31",
         lichun_time: "1976-02-05 00:39:28",
+        leap_month: 8,
+        new_moons: &[
+            "1976-01-31",
+            "1976-03-01",
+            "1976-03-31",
+            "1976-04-29",
+            "1976-05-29",
+            "1976-06-27",
+            "1976-07-27",
+            "1976-08-25",
+            "1976-09-24",
+            "1976-10-23",
+            "1976-11-21",
+            "1976-12-21",
+            "1977-01-19",
+            "1977-02-18",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -7950,6 +9213,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1977,
         lunar_new_year: "1977-02-18",
         lichun_time: "1977-02-04 06:33:25",
+        leap_month: 0,
+        new_moons: &[
+            "1977-02-18",
+            "1977-03-20",
+            "1977-04-18",
+            "1977-05-18",
+            "1977-06-17",
+            "1977-07-16",
+            "1977-08-15",
+            "1977-09-13",
+            "1977-10-13",
+            "1977-11-11",
+            "1977-12-11",
+            "1978-01-09",
+            "1978-02-07",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8053,6 +9332,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1978,
         lunar_new_year: "1978-02-07",
         lichun_time: "1978-02-04 12:26:57",
+        leap_month: 0,
+        new_moons: &[
+            "1978-02-07",
+            "1978-03-09",
+            "1978-04-07",
+            "1978-05-07",
+            "1978-06-06",
+            "1978-07-05",
+            "1978-08-04",
+            "1978-09-03",
+            "1978-10-02",
+            "1978-11-01",
+            "1978-11-30",
+            "1978-12-30",
+            "1979-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8156,6 +9451,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1979,
         lunar_new_year: "1979-01-28",
         lichun_time: "1979-02-04 18:12:18",
+        leap_month: 6,
+        new_moons: &[
+            "1979-01-28",
+            "1979-02-27",
+            "1979-03-28",
+            "1979-04-26",
+            "1979-05-26",
+            "1979-06-24",
+            "1979-07-24",
+            "1979-08-23",
+            "1979-09-21",
+            "1979-10-21",
+            "1979-11-20",
+            "1979-12-19",
+            "1980-01-18",
+            "1980-02-16",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8259,6 +9571,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1980,
         lunar_new_year: "1980-02-16",
         lichun_time: "1980-02-05 00:09:28",
+        leap_month: 0,
+        new_moons: &[
+            "1980-02-16",
+            "1980-03-17",
+            "1980-04-15",
+            "1980-05-14",
+            "1980-06-13",
+            "1980-07-12",
+            "1980-08-11",
+            "1980-09-09",
+            "1980-10-09",
+            "1980-11-08",
+            "1980-12-07",
+            "1981-01-06",
+            "1981-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8362,6 +9690,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1981,
         lunar_new_year: "1981-02-05",
         lichun_time: "1981-02-04 05:55:23",
+        leap_month: 0,
+        new_moons: &[
+            "1981-02-05",
+            "1981-03-06",
+            "1981-04-05",
+            "1981-05-04",
+            "1981-06-02",
+            "1981-07-02",
+            "1981-07-31",
+            "1981-08-29",
+            "1981-09-28",
+            "1981-10-28",
+            "1981-11-26",
+            "1981-12-26",
+            "1982-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8465,6 +9809,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1982,
         lunar_new_year: "1982-01-25",
         lichun_time: "1982-02-04 11:45:28",
+        leap_month: 4,
+        new_moons: &[
+            "1982-01-25",
+            "1982-02-24",
+            "1982-03-25",
+            "1982-04-24",
+            "1982-05-23",
+            "1982-06-21",
+            "1982-07-21",
+            "1982-08-19",
+            "1982-09-17",
+            "1982-10-17",
+            "1982-11-15",
+            "1982-12-15",
+            "1983-01-14",
+            "1983-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8568,6 +9929,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1983,
         lunar_new_year: "1983-02-13",
         lichun_time: "1983-02-04 17:39:42",
+        leap_month: 0,
+        new_moons: &[
+            "1983-02-13",
+            "1983-03-15",
+            "1983-04-13",
+            "1983-05-13",
+            "1983-06-11",
+            "1983-07-10",
+            "1983-08-09",
+            "1983-09-07",
+            "1983-10-06",
+            "1983-11-05",
+            "1983-12-04",
+            "1984-01-03",
+            "1984-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8671,6 +10048,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1984,
         lunar_new_year: "1984-02-02",
         lichun_time: "1984-02-04 23:18:44",
+        leap_month: 10,
+        new_moons: &[
+            "1984-02-02",
+            "1984-03-03",
+            "1984-04-01",
+            "1984-05-01",
+            "1984-05-31",
+            "1984-06-29",
+            "1984-07-28",
+            "1984-08-27",
+            "1984-09-25",
+            "1984-10-24",
+            "1984-11-23",
+            "1984-12-22",
+            "1985-01-21",
+            "1985-02-20",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8774,6 +10168,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1985,
         lunar_new_year: "1985-02-20",
         lichun_time: "1985-02-04 05:11:47",
+        leap_month: 0,
+        new_moons: &[
+            "1985-02-20",
+            "1985-03-21",
+            "1985-04-20",
+            "1985-05-20",
+            "1985-06-18",
+            "1985-07-18",
+            "1985-08-16",
+            "1985-09-15",
+            "1985-10-14",
+            "1985-11-12",
+            "1985-12-12",
+            "1986-01-10",
+            "1986-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8877,6 +10287,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1986,
         lunar_new_year: "1986-02-09",
         lichun_time: "1986-02-04 11:07:42",
+        leap_month: 0,
+        new_moons: &[
+            "1986-02-09",
+            "1986-03-10",
+            "1986-04-09",
+            "1986-05-09",
+            "1986-06-07",
+            "1986-07-07",
+            "1986-08-06",
+            "1986-09-04",
+            "1986-10-04",
+            "1986-11-02",
+            "1986-12-02",
+            "1986-12-31",
+            "1987-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -8980,6 +10406,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1987,
         lunar_new_year: "1987-01-29",
         lichun_time: "1987-02-04 16:51:40",
+        leap_month: 6,
+        new_moons: &[
+            "1987-01-29",
+            "1987-02-28",
+            "1987-03-29",
+            "1987-04-28",
+            "1987-05-27",
+            "1987-06-26",
+            "1987-07-26",
+            "1987-08-24",
+            "1987-09-23",
+            "1987-10-23",
+            "1987-11-21",
+            "1987-12-21",
+            "1988-01-19",
+            "1988-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9083,6 +10526,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1988,
         lunar_new_year: "1988-02-17",
         lichun_time: "1988-02-04 22:42:49",
+        leap_month: 0,
+        new_moons: &[
+            "1988-02-17",
+            "1988-03-18",
+            "1988-04-16",
+            "1988-05-16",
+            "1988-06-14",
+            "1988-07-14",
+            "1988-08-12",
+            "1988-09-11",
+            "1988-10-11",
+            "1988-11-09",
+            "1988-12-09",
+            "1989-01-08",
+            "1989-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9186,6 +10645,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1989,
         lunar_new_year: "1989-02-06",
         lichun_time: "1989-02-04 04:27:09",
+        leap_month: 0,
+        new_moons: &[
+            "1989-02-06",
+            "1989-03-08",
+            "1989-04-06",
+            "1989-05-05",
+            "1989-06-04",
+            "1989-07-03",
+            "1989-08-02",
+            "1989-08-31",
+            "1989-09-30",
+            "1989-10-29",
+            "1989-11-28",
+            "1989-12-28",
+            "1990-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9289,6 +10764,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1990,
         lunar_new_year: "1990-01-27",
         lichun_time: "1990-02-04 10:14:00",
+        leap_month: 5,
+        new_moons: &[
+            "1990-01-27",
+            "1990-02-25",
+            "1990-03-27",
+            "1990-04-25",
+            "1990-05-24",
+            "1990-06-23",
+            "1990-07-22",
+            "1990-08-20",
+            "1990-09-19",
+            "1990-10-18",
+            "1990-11-17",
+            "1990-12-17",
+            "1991-01-16",
+            "1991-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9392,6 +10884,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1991,
         lunar_new_year: "1991-02-15",
         lichun_time: "1991-02-04 16:08:24",
+        leap_month: 0,
+        new_moons: &[
+            "1991-02-15",
+            "1991-03-16",
+            "1991-04-15",
+            "1991-05-14",
+            "1991-06-12",
+            "1991-07-12",
+            "1991-08-10",
+            "1991-09-08",
+            "1991-10-08",
+            "1991-11-06",
+            "1991-12-06",
+            "1992-01-05",
+            "1992-02-04",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9495,6 +11003,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1992,
         lunar_new_year: "1992-02-04",
         lichun_time: "1992-02-04 21:48:17",
+        leap_month: 0,
+        new_moons: &[
+            "1992-02-04",
+            "1992-03-04",
+            "1992-04-03",
+            "1992-05-03",
+            "1992-06-01",
+            "1992-06-30",
+            "1992-07-30",
+            "1992-08-28",
+            "1992-09-26",
+            "1992-10-26",
+            "1992-11-24",
+            "1992-12-24",
+            "1993-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9598,6 +11122,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1993,
         lunar_new_year: "1993-01-23",
         lichun_time: "1993-02-04 03:37:09",
+        leap_month: 3,
+        new_moons: &[
+            "1993-01-23",
+            "1993-02-21",
+            "1993-03-23",
+            "1993-04-22",
+            "1993-05-21",
+            "1993-06-20",
+            "1993-07-19",
+            "1993-08-18",
+            "1993-09-16",
+            "1993-10-15",
+            "1993-11-14",
+            "1993-12-13",
+            "1994-01-12",
+            "1994-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9701,6 +11242,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1994,
         lunar_new_year: "1994-02-10",
         lichun_time: "1994-02-04 09:30:56",
+        leap_month: 0,
+        new_moons: &[
+            "1994-02-10",
+            "1994-03-12",
+            "1994-04-11",
+            "1994-05-11",
+            "1994-06-09",
+            "1994-07-09",
+            "1994-08-07",
+            "1994-09-06",
+            "1994-10-05",
+            "1994-11-03",
+            "1994-12-03",
+            "1995-01-01",
+            "1995-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9804,6 +11361,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1995,
         lunar_new_year: "1995-01-31",
         lichun_time: "1995-02-04 15:12:51",
+        leap_month: 8,
+        new_moons: &[
+            "1995-01-31",
+            "1995-03-01",
+            "1995-03-31",
+            "1995-04-30",
+            "1995-05-29",
+            "1995-06-28",
+            "1995-07-27",
+            "1995-08-26",
+            "1995-09-25",
+            "1995-10-24",
+            "1995-11-22",
+            "1995-12-22",
+            "1996-01-20",
+            "1996-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -9907,6 +11481,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1996,
         lunar_new_year: "1996-02-19",
         lichun_time: "1996-02-04 21:07:54",
+        leap_month: 0,
+        new_moons: &[
+            "1996-02-19",
+            "1996-03-19",
+            "1996-04-18",
+            "1996-05-17",
+            "1996-06-16",
+            "1996-07-16",
+            "1996-08-14",
+            "1996-09-13",
+            "1996-10-12",
+            "1996-11-11",
+            "1996-12-11",
+            "1997-01-09",
+            "1997-02-07",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10010,6 +11600,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1997,
         lunar_new_year: "1997-02-07",
         lichun_time: "1997-02-04 03:01:57",
+        leap_month: 0,
+        new_moons: &[
+            "1997-02-07",
+            "1997-03-09",
+            "1997-04-07",
+            "1997-05-07",
+            "1997-06-05",
+            "1997-07-05",
+            "1997-08-03",
+            "1997-09-02",
+            "1997-10-02",
+            "1997-10-31",
+            "1997-11-30",
+            "1997-12-30",
+            "1998-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10113,6 +11719,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1998,
         lunar_new_year: "1998-01-28",
         lichun_time: "1998-02-04 08:56:52",
+        leap_month: 5,
+        new_moons: &[
+            "1998-01-28",
+            "1998-02-27",
+            "1998-03-28",
+            "1998-04-26",
+            "1998-05-26",
+            "1998-06-24",
+            "1998-07-23",
+            "1998-08-22",
+            "1998-09-21",
+            "1998-10-20",
+            "1998-11-19",
+            "1998-12-19",
+            "1999-01-17",
+            "1999-02-16",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10216,6 +11839,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 1999,
         lunar_new_year: "1999-02-16",
         lichun_time: "1999-02-04 14:57:03",
+        leap_month: 0,
+        new_moons: &[
+            "1999-02-16",
+            "1999-03-18",
+            "1999-04-16",
+            "1999-05-15",
+            "1999-06-14",
+            "1999-07-13",
+            "1999-08-11",
+            "1999-09-10",
+            "1999-10-09",
+            "1999-11-08",
+            "1999-12-08",
+            "2000-01-07",
+            "2000-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10319,6 +11958,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2000,
         lunar_new_year: "2000-02-05",
         lichun_time: "2000-02-04 20:40:24",
+        leap_month: 0,
+        new_moons: &[
+            "2000-02-05",
+            "2000-03-06",
+            "2000-04-05",
+            "2000-05-04",
+            "2000-06-02",
+            "2000-07-02",
+            "2000-07-31",
+            "2000-08-29",
+            "2000-09-28",
+            "2000-10-27",
+            "2000-11-26",
+            "2000-12-26",
+            "2001-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10422,6 +12077,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2001,
         lunar_new_year: "2001-01-24",
         lichun_time: "2001-02-04 02:28:49",
+        leap_month: 4,
+        new_moons: &[
+            "2001-01-24",
+            "2001-02-23",
+            "2001-03-25",
+            "2001-04-23",
+            "2001-05-23",
+            "2001-06-21",
+            "2001-07-21",
+            "2001-08-19",
+            "2001-09-17",
+            "2001-10-17",
+            "2001-11-15",
+            "2001-12-15",
+            "2002-01-13",
+            "2002-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10525,6 +12197,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2002,
         lunar_new_year: "2002-02-12",
         lichun_time: "2002-02-04 08:24:05",
+        leap_month: 0,
+        new_moons: &[
+            "2002-02-12",
+            "2002-03-14",
+            "2002-04-13",
+            "2002-05-12",
+            "2002-06-11",
+            "2002-07-10",
+            "2002-08-09",
+            "2002-09-07",
+            "2002-10-06",
+            "2002-11-05",
+            "2002-12-04",
+            "2003-01-03",
+            "2003-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10628,6 +12316,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2003,
         lunar_new_year: "2003-02-01",
         lichun_time: "2003-02-04 14:05:20",
+        leap_month: 0,
+        new_moons: &[
+            "2003-02-01",
+            "2003-03-03",
+            "2003-04-02",
+            "2003-05-01",
+            "2003-05-31",
+            "2003-06-30",
+            "2003-07-29",
+            "2003-08-28",
+            "2003-09-26",
+            "2003-10-25",
+            "2003-11-24",
+            "2003-12-23",
+            "2004-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10731,6 +12435,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2004,
         lunar_new_year: "2004-01-22",
         lichun_time: "2004-02-04 19:56:13",
+        leap_month: 2,
+        new_moons: &[
+            "2004-01-22",
+            "2004-02-20",
+            "2004-03-21",
+            "2004-04-19",
+            "2004-05-19",
+            "2004-06-18",
+            "2004-07-17",
+            "2004-08-16",
+            "2004-09-14",
+            "2004-10-14",
+            "2004-11-12",
+            "2004-12-12",
+            "2005-01-10",
+            "2005-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10834,6 +12555,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2005,
         lunar_new_year: "2005-02-09",
         lichun_time: "2005-02-04 01:43:02",
+        leap_month: 0,
+        new_moons: &[
+            "2005-02-09",
+            "2005-03-10",
+            "2005-04-09",
+            "2005-05-08",
+            "2005-06-07",
+            "2005-07-06",
+            "2005-08-05",
+            "2005-09-04",
+            "2005-10-03",
+            "2005-11-02",
+            "2005-12-01",
+            "2005-12-31",
+            "2006-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -10937,6 +12674,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2006,
         lunar_new_year: "2006-01-29",
         lichun_time: "2006-02-04 07:27:16",
+        leap_month: 7,
+        new_moons: &[
+            "2006-01-29",
+            "2006-02-28",
+            "2006-03-29",
+            "2006-04-28",
+            "2006-05-27",
+            "2006-06-26",
+            "2006-07-25",
+            "2006-08-24",
+            "2006-09-22",
+            "2006-10-22",
+            "2006-11-21",
+            "2006-12-20",
+            "2007-01-19",
+            "2007-02-18",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11040,6 +12794,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2007,
         lunar_new_year: "2007-02-18",
         lichun_time: "2007-02-04 13:18:12",
+        leap_month: 0,
+        new_moons: &[
+            "2007-02-18",
+            "2007-03-19",
+            "2007-04-17",
+            "2007-05-17",
+            "2007-06-15",
+            "2007-07-14",
+            "2007-08-13",
+            "2007-09-11",
+            "2007-10-11",
+            "2007-11-10",
+            "2007-12-10",
+            "2008-01-08",
+            "2008-02-07",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11143,6 +12913,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2008,
         lunar_new_year: "2008-02-07",
         lichun_time: "2008-02-04 19:00:24",
+        leap_month: 0,
+        new_moons: &[
+            "2008-02-07",
+            "2008-03-08",
+            "2008-04-06",
+            "2008-05-05",
+            "2008-06-04",
+            "2008-07-03",
+            "2008-08-01",
+            "2008-08-31",
+            "2008-09-29",
+            "2008-10-29",
+            "2008-11-28",
+            "2008-12-27",
+            "2009-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11246,6 +13032,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2009,
         lunar_new_year: "2009-01-26",
         lichun_time: "2009-02-04 00:49:48",
+        leap_month: 5,
+        new_moons: &[
+            "2009-01-26",
+            "2009-02-25",
+            "2009-03-27",
+            "2009-04-25",
+            "2009-05-24",
+            "2009-06-23",
+            "2009-07-22",
+            "2009-08-20",
+            "2009-09-19",
+            "2009-10-18",
+            "2009-11-17",
+            "2009-12-16",
+            "2010-01-15",
+            "2010-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11349,6 +13152,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2010,
         lunar_new_year: "2010-02-14",
         lichun_time: "2010-02-04 06:47:51",
+        leap_month: 0,
+        new_moons: &[
+            "2010-02-14",
+            "2010-03-16",
+            "2010-04-14",
+            "2010-05-14",
+            "2010-06-12",
+            "2010-07-12",
+            "2010-08-10",
+            "2010-09-08",
+            "2010-10-08",
+            "2010-11-06",
+            "2010-12-06",
+            "2011-01-04",
+            "2011-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11452,6 +13271,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2011,
         lunar_new_year: "2011-02-03",
         lichun_time: "2011-02-04 12:32:56",
+        leap_month: 0,
+        new_moons: &[
+            "2011-02-03",
+            "2011-03-05",
+            "2011-04-03",
+            "2011-05-03",
+            "2011-06-02",
+            "2011-07-01",
+            "2011-07-31",
+            "2011-08-29",
+            "2011-09-27",
+            "2011-10-27",
+            "2011-11-25",
+            "2011-12-25",
+            "2012-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11555,6 +13390,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2012,
         lunar_new_year: "2012-01-23",
         lichun_time: "2012-02-04 18:22:24",
+        leap_month: 4,
+        new_moons: &[
+            "2012-01-23",
+            "2012-02-22",
+            "2012-03-22",
+            "2012-04-21",
+            "2012-05-21",
+            "2012-06-19",
+            "2012-07-19",
+            "2012-08-17",
+            "2012-09-16",
+            "2012-10-15",
+            "2012-11-14",
+            "2012-12-13",
+            "2013-01-12",
+            "2013-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11658,6 +13510,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2013,
         lunar_new_year: "2013-02-10",
         lichun_time: "2013-02-04 00:13:25",
+        leap_month: 0,
+        new_moons: &[
+            "2013-02-10",
+            "2013-03-12",
+            "2013-04-10",
+            "2013-05-10",
+            "2013-06-08",
+            "2013-07-08",
+            "2013-08-07",
+            "2013-09-05",
+            "2013-10-05",
+            "2013-11-03",
+            "2013-12-03",
+            "2014-01-01",
+            "2014-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11761,6 +13629,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2014,
         lunar_new_year: "2014-01-31",
         lichun_time: "2014-02-04 06:03:16",
+        leap_month: 9,
+        new_moons: &[
+            "2014-01-31",
+            "2014-03-01",
+            "2014-03-31",
+            "2014-04-29",
+            "2014-05-29",
+            "2014-06-27",
+            "2014-07-27",
+            "2014-08-25",
+            "2014-09-24",
+            "2014-10-24",
+            "2014-11-22",
+            "2014-12-22",
+            "2015-01-20",
+            "2015-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11864,6 +13749,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2015,
         lunar_new_year: "2015-02-19",
         lichun_time: "2015-02-04 11:58:27",
+        leap_month: 0,
+        new_moons: &[
+            "2015-02-19",
+            "2015-03-20",
+            "2015-04-19",
+            "2015-05-18",
+            "2015-06-16",
+            "2015-07-16",
+            "2015-08-14",
+            "2015-09-13",
+            "2015-10-13",
+            "2015-11-12",
+            "2015-12-11",
+            "2016-01-10",
+            "2016-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -11967,6 +13868,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2016,
         lunar_new_year: "2016-02-08",
         lichun_time: "2016-02-04 17:46:03",
+        leap_month: 0,
+        new_moons: &[
+            "2016-02-08",
+            "2016-03-09",
+            "2016-04-07",
+            "2016-05-07",
+            "2016-06-05",
+            "2016-07-04",
+            "2016-08-03",
+            "2016-09-01",
+            "2016-10-01",
+            "2016-10-31",
+            "2016-11-29",
+            "2016-12-29",
+            "2017-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12070,6 +13987,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2017,
         lunar_new_year: "2017-01-28",
         lichun_time: "2017-02-03 23:34:04",
+        leap_month: 6,
+        new_moons: &[
+            "2017-01-28",
+            "2017-02-26",
+            "2017-03-28",
+            "2017-04-26",
+            "2017-05-26",
+            "2017-06-24",
+            "2017-07-23",
+            "2017-08-22",
+            "2017-09-20",
+            "2017-10-20",
+            "2017-11-18",
+            "2017-12-18",
+            "2018-01-17",
+            "2018-02-16",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12173,6 +14107,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2018,
         lunar_new_year: "2018-02-16",
         lichun_time: "2018-02-04 05:28:30",
+        leap_month: 0,
+        new_moons: &[
+            "2018-02-16",
+            "2018-03-17",
+            "2018-04-16",
+            "2018-05-15",
+            "2018-06-14",
+            "2018-07-13",
+            "2018-08-11",
+            "2018-09-10",
+            "2018-10-09",
+            "2018-11-08",
+            "2018-12-07",
+            "2019-01-06",
+            "2019-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12276,6 +14226,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2019,
         lunar_new_year: "2019-02-05",
         lichun_time: "2019-02-04 11:14:21",
+        leap_month: 0,
+        new_moons: &[
+            "2019-02-05",
+            "2019-03-07",
+            "2019-04-05",
+            "2019-05-05",
+            "2019-06-03",
+            "2019-07-03",
+            "2019-08-01",
+            "2019-08-30",
+            "2019-09-29",
+            "2019-10-28",
+            "2019-11-26",
+            "2019-12-26",
+            "2020-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12379,6 +14345,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2020,
         lunar_new_year: "2020-01-25",
         lichun_time: "2020-02-04 17:03:19",
+        leap_month: 4,
+        new_moons: &[
+            "2020-01-25",
+            "2020-02-23",
+            "2020-03-24",
+            "2020-04-23",
+            "2020-05-23",
+            "2020-06-21",
+            "2020-07-21",
+            "2020-08-19",
+            "2020-09-17",
+            "2020-10-17",
+            "2020-11-15",
+            "2020-12-15",
+            "2021-01-13",
+            "2021-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12482,6 +14465,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2021,
         lunar_new_year: "2021-02-12",
         lichun_time: "2021-02-03 22:58:48",
+        leap_month: 0,
+        new_moons: &[
+            "2021-02-12",
+            "2021-03-13",
+            "2021-04-12",
+            "2021-05-12",
+            "2021-06-10",
+            "2021-07-10",
+            "2021-08-08",
+            "2021-09-07",
+            "2021-10-06",
+            "2021-11-05",
+            "2021-12-04",
+            "2022-01-03",
+            "2022-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12585,6 +14584,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2022,
         lunar_new_year: "2022-02-01",
         lichun_time: "2022-02-04 04:50:47",
+        leap_month: 0,
+        new_moons: &[
+            "2022-02-01",
+            "2022-03-03",
+            "2022-04-01",
+            "2022-05-01",
+            "2022-05-30",
+            "2022-06-29",
+            "2022-07-29",
+            "2022-08-27",
+            "2022-09-26",
+            "2022-10-25",
+            "2022-11-24",
+            "2022-12-23",
+            "2023-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12688,6 +14703,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2023,
         lunar_new_year: "2023-01-22",
         lichun_time: "2023-02-04 10:42:33",
+        leap_month: 2,
+        new_moons: &[
+            "2023-01-22",
+            "2023-02-20",
+            "2023-03-22",
+            "2023-04-20",
+            "2023-05-19",
+            "2023-06-18",
+            "2023-07-18",
+            "2023-08-16",
+            "2023-09-15",
+            "2023-10-15",
+            "2023-11-13",
+            "2023-12-13",
+            "2024-01-11",
+            "2024-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12791,6 +14823,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2024,
         lunar_new_year: "2024-02-10",
         lichun_time: "2024-02-04 16:27:07",
+        leap_month: 0,
+        new_moons: &[
+            "2024-02-10",
+            "2024-03-10",
+            "2024-04-09",
+            "2024-05-08",
+            "2024-06-06",
+            "2024-07-06",
+            "2024-08-04",
+            "2024-09-03",
+            "2024-10-03",
+            "2024-11-01",
+            "2024-12-01",
+            "2024-12-31",
+            "2025-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12894,6 +14942,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2025,
         lunar_new_year: "2025-01-29",
         lichun_time: "2025-02-03 22:10:28",
+        leap_month: 6,
+        new_moons: &[
+            "2025-01-29",
+            "2025-02-28",
+            "2025-03-29",
+            "2025-04-28",
+            "2025-05-27",
+            "2025-06-25",
+            "2025-07-25",
+            "2025-08-23",
+            "2025-09-22",
+            "2025-10-21",
+            "2025-11-20",
+            "2025-12-20",
+            "2026-01-19",
+            "2026-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -12997,6 +15062,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2026,
         lunar_new_year: "2026-02-17",
         lichun_time: "2026-02-04 04:02:08",
+        leap_month: 0,
+        new_moons: &[
+            "2026-02-17",
+            "2026-03-19",
+            "2026-04-17",
+            "2026-05-17",
+            "2026-06-15",
+            "2026-07-14",
+            "2026-08-13",
+            "2026-09-11",
+            "2026-10-10",
+            "2026-11-09",
+            "2026-12-09",
+            "2027-01-08",
+            "2027-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13100,6 +15181,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2027,
         lunar_new_year: "2027-02-06",
         lichun_time: "2027-02-04 09:46:18",
+        leap_month: 0,
+        new_moons: &[
+            "2027-02-06",
+            "2027-03-08",
+            "2027-04-07",
+            "2027-05-06",
+            "2027-06-05",
+            "2027-07-04",
+            "2027-08-02",
+            "2027-09-01",
+            "2027-09-30",
+            "2027-10-29",
+            "2027-11-28",
+            "2027-12-28",
+            "2028-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13203,6 +15300,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2028,
         lunar_new_year: "2028-01-26",
         lichun_time: "2028-02-04 15:31:13",
+        leap_month: 5,
+        new_moons: &[
+            "2028-01-26",
+            "2028-02-25",
+            "2028-03-26",
+            "2028-04-25",
+            "2028-05-24",
+            "2028-06-23",
+            "2028-07-22",
+            "2028-08-20",
+            "2028-09-19",
+            "2028-10-18",
+            "2028-11-16",
+            "2028-12-16",
+            "2029-01-15",
+            "2029-02-13",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13306,6 +15420,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2029,
         lunar_new_year: "2029-02-13",
         lichun_time: "2029-02-03 21:20:47",
+        leap_month: 0,
+        new_moons: &[
+            "2029-02-13",
+            "2029-03-15",
+            "2029-04-14",
+            "2029-05-13",
+            "2029-06-12",
+            "2029-07-11",
+            "2029-08-10",
+            "2029-09-08",
+            "2029-10-08",
+            "2029-11-06",
+            "2029-12-05",
+            "2030-01-04",
+            "2030-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13409,6 +15539,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2030,
         lunar_new_year: "2030-02-03",
         lichun_time: "2030-02-04 03:08:28",
+        leap_month: 0,
+        new_moons: &[
+            "2030-02-03",
+            "2030-03-04",
+            "2030-04-03",
+            "2030-05-02",
+            "2030-06-01",
+            "2030-07-01",
+            "2030-07-30",
+            "2030-08-29",
+            "2030-09-27",
+            "2030-10-27",
+            "2030-11-25",
+            "2030-12-25",
+            "2031-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13512,6 +15658,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2031,
         lunar_new_year: "2031-01-23",
         lichun_time: "2031-02-04 08:58:19",
+        leap_month: 3,
+        new_moons: &[
+            "2031-01-23",
+            "2031-02-21",
+            "2031-03-23",
+            "2031-04-22",
+            "2031-05-21",
+            "2031-06-20",
+            "2031-07-19",
+            "2031-08-18",
+            "2031-09-17",
+            "2031-10-16",
+            "2031-11-15",
+            "2031-12-14",
+            "2032-01-13",
+            "2032-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13615,6 +15778,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2032,
         lunar_new_year: "2032-02-11",
         lichun_time: "2032-02-04 14:48:59",
+        leap_month: 0,
+        new_moons: &[
+            "2032-02-11",
+            "2032-03-12",
+            "2032-04-10",
+            "2032-05-09",
+            "2032-06-08",
+            "2032-07-07",
+            "2032-08-06",
+            "2032-09-05",
+            "2032-10-04",
+            "2032-11-03",
+            "2032-12-03",
+            "2033-01-01",
+            "2033-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13718,6 +15897,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2033,
         lunar_new_year: "2033-01-31",
         lichun_time: "2033-02-03 20:41:36",
+        leap_month: 11,
+        new_moons: &[
+            "2033-01-31",
+            "2033-03-01",
+            "2033-03-31",
+            "2033-04-29",
+            "2033-05-28",
+            "2033-06-27",
+            "2033-07-26",
+            "2033-08-25",
+            "2033-09-23",
+            "2033-10-23",
+            "2033-11-22",
+            "2033-12-22",
+            "2034-01-20",
+            "2034-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13821,6 +16017,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2034,
         lunar_new_year: "2034-02-19",
         lichun_time: "2034-02-04 02:41:10",
+        leap_month: 0,
+        new_moons: &[
+            "2034-02-19",
+            "2034-03-20",
+            "2034-04-19",
+            "2034-05-18",
+            "2034-06-16",
+            "2034-07-16",
+            "2034-08-14",
+            "2034-09-13",
+            "2034-10-12",
+            "2034-11-11",
+            "2034-12-11",
+            "2035-01-09",
+            "2035-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -13924,6 +16136,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2035,
         lunar_new_year: "2035-02-08",
         lichun_time: "2035-02-04 08:31:35",
+        leap_month: 0,
+        new_moons: &[
+            "2035-02-08",
+            "2035-03-10",
+            "2035-04-08",
+            "2035-05-08",
+            "2035-06-06",
+            "2035-07-05",
+            "2035-08-04",
+            "2035-09-02",
+            "2035-10-01",
+            "2035-10-31",
+            "2035-11-30",
+            "2035-12-29",
+            "2036-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14027,6 +16255,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2036,
         lunar_new_year: "2036-01-28",
         lichun_time: "2036-02-04 14:19:57",
+        leap_month: 6,
+        new_moons: &[
+            "2036-01-28",
+            "2036-02-27",
+            "2036-03-28",
+            "2036-04-26",
+            "2036-05-26",
+            "2036-06-24",
+            "2036-07-23",
+            "2036-08-22",
+            "2036-09-20",
+            "2036-10-19",
+            "2036-11-18",
+            "2036-12-17",
+            "2037-01-16",
+            "2037-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14130,6 +16375,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2037,
         lunar_new_year: "2037-02-15",
         lichun_time: "2037-02-03 20:11:39",
+        leap_month: 0,
+        new_moons: &[
+            "2037-02-15",
+            "2037-03-17",
+            "2037-04-16",
+            "2037-05-15",
+            "2037-06-14",
+            "2037-07-13",
+            "2037-08-11",
+            "2037-09-10",
+            "2037-10-09",
+            "2037-11-07",
+            "2037-12-07",
+            "2038-01-05",
+            "2038-02-04",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14233,6 +16494,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2038,
         lunar_new_year: "2038-02-04",
         lichun_time: "2038-02-04 02:03:48",
+        leap_month: 0,
+        new_moons: &[
+            "2038-02-04",
+            "2038-03-06",
+            "2038-04-05",
+            "2038-05-04",
+            "2038-06-03",
+            "2038-07-02",
+            "2038-08-01",
+            "2038-08-30",
+            "2038-09-29",
+            "2038-10-28",
+            "2038-11-26",
+            "2038-12-26",
+            "2039-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14336,6 +16613,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2039,
         lunar_new_year: "2039-01-24",
         lichun_time: "2039-02-04 07:52:56",
+        leap_month: 5,
+        new_moons: &[
+            "2039-01-24",
+            "2039-02-23",
+            "2039-03-25",
+            "2039-04-23",
+            "2039-05-23",
+            "2039-06-22",
+            "2039-07-21",
+            "2039-08-20",
+            "2039-09-18",
+            "2039-10-18",
+            "2039-11-16",
+            "2039-12-16",
+            "2040-01-14",
+            "2040-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14439,6 +16733,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2040,
         lunar_new_year: "2040-02-12",
         lichun_time: "2040-02-04 13:39:56",
+        leap_month: 0,
+        new_moons: &[
+            "2040-02-12",
+            "2040-03-13",
+            "2040-04-11",
+            "2040-05-11",
+            "2040-06-10",
+            "2040-07-09",
+            "2040-08-08",
+            "2040-09-06",
+            "2040-10-06",
+            "2040-11-05",
+            "2040-12-04",
+            "2041-01-03",
+            "2041-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14542,6 +16852,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2041,
         lunar_new_year: "2041-02-01",
         lichun_time: "2041-02-03 19:25:11",
+        leap_month: 0,
+        new_moons: &[
+            "2041-02-01",
+            "2041-03-02",
+            "2041-04-01",
+            "2041-04-30",
+            "2041-05-30",
+            "2041-06-28",
+            "2041-07-28",
+            "2041-08-27",
+            "2041-09-25",
+            "2041-10-25",
+            "2041-11-24",
+            "2041-12-23",
+            "2042-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14645,6 +16971,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2042,
         lunar_new_year: "2042-01-22",
         lichun_time: "2042-02-04 01:12:53",
+        leap_month: 2,
+        new_moons: &[
+            "2042-01-22",
+            "2042-02-20",
+            "2042-03-22",
+            "2042-04-20",
+            "2042-05-19",
+            "2042-06-18",
+            "2042-07-17",
+            "2042-08-16",
+            "2042-09-14",
+            "2042-10-14",
+            "2042-11-13",
+            "2042-12-12",
+            "2043-01-11",
+            "2043-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14748,6 +17091,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2043,
         lunar_new_year: "2043-02-10",
         lichun_time: "2043-02-04 06:58:48",
+        leap_month: 0,
+        new_moons: &[
+            "2043-02-10",
+            "2043-03-11",
+            "2043-04-10",
+            "2043-05-09",
+            "2043-06-07",
+            "2043-07-07",
+            "2043-08-05",
+            "2043-09-03",
+            "2043-10-03",
+            "2043-11-02",
+            "2043-12-01",
+            "2043-12-31",
+            "2044-01-30",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14851,6 +17210,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2044,
         lunar_new_year: "2044-01-30",
         lichun_time: "2044-02-04 12:44:21",
+        leap_month: 7,
+        new_moons: &[
+            "2044-01-30",
+            "2044-02-29",
+            "2044-03-29",
+            "2044-04-28",
+            "2044-05-27",
+            "2044-06-25",
+            "2044-07-25",
+            "2044-08-23",
+            "2044-09-21",
+            "2044-10-21",
+            "2044-11-19",
+            "2044-12-19",
+            "2045-01-18",
+            "2045-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -14954,6 +17330,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2045,
         lunar_new_year: "2045-02-17",
         lichun_time: "2045-02-03 18:36:22",
+        leap_month: 0,
+        new_moons: &[
+            "2045-02-17",
+            "2045-03-19",
+            "2045-04-17",
+            "2045-05-17",
+            "2045-06-15",
+            "2045-07-14",
+            "2045-08-13",
+            "2045-09-11",
+            "2045-10-10",
+            "2045-11-09",
+            "2045-12-08",
+            "2046-01-07",
+            "2046-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15057,6 +17449,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2046,
         lunar_new_year: "2046-02-06",
         lichun_time: "2046-02-04 00:31:09",
+        leap_month: 0,
+        new_moons: &[
+            "2046-02-06",
+            "2046-03-08",
+            "2046-04-06",
+            "2046-05-06",
+            "2046-06-04",
+            "2046-07-04",
+            "2046-08-02",
+            "2046-09-01",
+            "2046-09-30",
+            "2046-10-29",
+            "2046-11-28",
+            "2046-12-27",
+            "2047-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15160,6 +17568,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2047,
         lunar_new_year: "2047-01-26",
         lichun_time: "2047-02-04 06:18:05",
+        leap_month: 5,
+        new_moons: &[
+            "2047-01-26",
+            "2047-02-25",
+            "2047-03-26",
+            "2047-04-25",
+            "2047-05-25",
+            "2047-06-23",
+            "2047-07-23",
+            "2047-08-21",
+            "2047-09-20",
+            "2047-10-19",
+            "2047-11-17",
+            "2047-12-17",
+            "2048-01-15",
+            "2048-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15263,6 +17688,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2048,
         lunar_new_year: "2048-02-14",
         lichun_time: "2048-02-04 12:04:44",
+        leap_month: 0,
+        new_moons: &[
+            "2048-02-14",
+            "2048-03-14",
+            "2048-04-13",
+            "2048-05-13",
+            "2048-06-11",
+            "2048-07-11",
+            "2048-08-10",
+            "2048-09-08",
+            "2048-10-08",
+            "2048-11-06",
+            "2048-12-05",
+            "2049-01-04",
+            "2049-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15366,6 +17807,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2049,
         lunar_new_year: "2049-02-02",
         lichun_time: "2049-02-03 17:53:27",
+        leap_month: 0,
+        new_moons: &[
+            "2049-02-02",
+            "2049-03-04",
+            "2049-04-02",
+            "2049-05-02",
+            "2049-05-31",
+            "2049-06-30",
+            "2049-07-30",
+            "2049-08-28",
+            "2049-09-27",
+            "2049-10-27",
+            "2049-11-25",
+            "2049-12-25",
+            "2050-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15469,6 +17926,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2050,
         lunar_new_year: "2050-01-23",
         lichun_time: "2050-02-03 23:43:54",
+        leap_month: 3,
+        new_moons: &[
+            "2050-01-23",
+            "2050-02-21",
+            "2050-03-23",
+            "2050-04-21",
+            "2050-05-21",
+            "2050-06-19",
+            "2050-07-19",
+            "2050-08-17",
+            "2050-09-16",
+            "2050-10-16",
+            "2050-11-14",
+            "2050-12-14",
+            "2051-01-13",
+            "2051-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15572,6 +18046,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2051,
         lunar_new_year: "2051-02-11",
         lichun_time: "2051-02-04 05:36:14",
+        leap_month: 0,
+        new_moons: &[
+            "2051-02-11",
+            "2051-03-13",
+            "2051-04-11",
+            "2051-05-10",
+            "2051-06-09",
+            "2051-07-08",
+            "2051-08-06",
+            "2051-09-05",
+            "2051-10-05",
+            "2051-11-03",
+            "2051-12-03",
+            "2052-01-02",
+            "2052-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15675,6 +18165,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2052,
         lunar_new_year: "2052-02-01",
         lichun_time: "2052-02-04 11:23:05",
+        leap_month: 8,
+        new_moons: &[
+            "2052-02-01",
+            "2052-03-01",
+            "2052-03-31",
+            "2052-04-29",
+            "2052-05-28",
+            "2052-06-27",
+            "2052-07-26",
+            "2052-08-24",
+            "2052-09-23",
+            "2052-10-22",
+            "2052-11-21",
+            "2052-12-21",
+            "2053-01-20",
+            "2053-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15778,6 +18285,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2053,
         lunar_new_year: "2053-02-19",
         lichun_time: "2053-02-03 17:13:09",
+        leap_month: 0,
+        new_moons: &[
+            "2053-02-19",
+            "2053-03-20",
+            "2053-04-19",
+            "2053-05-18",
+            "2053-06-16",
+            "2053-07-16",
+            "2053-08-14",
+            "2053-09-12",
+            "2053-10-12",
+            "2053-11-10",
+            "2053-12-10",
+            "2054-01-09",
+            "2054-02-08",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15881,6 +18404,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2054,
         lunar_new_year: "2054-02-08",
         lichun_time: "2054-02-03 23:08:01",
+        leap_month: 0,
+        new_moons: &[
+            "2054-02-08",
+            "2054-03-09",
+            "2054-04-08",
+            "2054-05-08",
+            "2054-06-06",
+            "2054-07-05",
+            "2054-08-04",
+            "2054-09-02",
+            "2054-10-01",
+            "2054-10-31",
+            "2054-11-29",
+            "2054-12-29",
+            "2055-01-28",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -15984,6 +18523,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2055,
         lunar_new_year: "2055-01-28",
         lichun_time: "2055-02-04 04:55:54",
+        leap_month: 6,
+        new_moons: &[
+            "2055-01-28",
+            "2055-02-26",
+            "2055-03-28",
+            "2055-04-27",
+            "2055-05-26",
+            "2055-06-25",
+            "2055-07-24",
+            "2055-08-23",
+            "2055-09-21",
+            "2055-10-20",
+            "2055-11-19",
+            "2055-12-18",
+            "2056-01-17",
+            "2056-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16087,6 +18643,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2056,
         lunar_new_year: "2056-02-15",
         lichun_time: "2056-02-04 10:47:14",
+        leap_month: 0,
+        new_moons: &[
+            "2056-02-15",
+            "2056-03-16",
+            "2056-04-15",
+            "2056-05-15",
+            "2056-06-13",
+            "2056-07-13",
+            "2056-08-11",
+            "2056-09-10",
+            "2056-10-09",
+            "2056-11-07",
+            "2056-12-07",
+            "2057-01-05",
+            "2057-02-04",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16190,6 +18762,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2057,
         lunar_new_year: "2057-02-04",
         lichun_time: "2057-02-03 16:42:35",
+        leap_month: 0,
+        new_moons: &[
+            "2057-02-04",
+            "2057-03-05",
+            "2057-04-04",
+            "2057-05-04",
+            "2057-06-02",
+            "2057-07-02",
+            "2057-07-31",
+            "2057-08-30",
+            "2057-09-29",
+            "2057-10-28",
+            "2057-11-26",
+            "2057-12-26",
+            "2058-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16293,6 +18881,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2058,
         lunar_new_year: "2058-01-24",
         lichun_time: "2058-02-03 22:34:35",
+        leap_month: 4,
+        new_moons: &[
+            "2058-01-24",
+            "2058-02-23",
+            "2058-03-24",
+            "2058-04-23",
+            "2058-05-22",
+            "2058-06-21",
+            "2058-07-20",
+            "2058-08-19",
+            "2058-09-18",
+            "2058-10-17",
+            "2058-11-16",
+            "2058-12-16",
+            "2059-01-14",
+            "2059-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16396,6 +19001,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2059,
         lunar_new_year: "2059-02-12",
         lichun_time: "2059-02-04 04:24:01",
+        leap_month: 0,
+        new_moons: &[
+            "2059-02-12",
+            "2059-03-14",
+            "2059-04-12",
+            "2059-05-12",
+            "2059-06-10",
+            "2059-07-10",
+            "2059-08-08",
+            "2059-09-07",
+            "2059-10-06",
+            "2059-11-05",
+            "2059-12-05",
+            "2060-01-04",
+            "2060-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16499,6 +19120,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2060,
         lunar_new_year: "2060-02-02",
         lichun_time: "2060-02-04 10:08:14",
+        leap_month: 0,
+        new_moons: &[
+            "2060-02-02",
+            "2060-03-03",
+            "2060-04-01",
+            "2060-04-30",
+            "2060-05-30",
+            "2060-06-28",
+            "2060-07-27",
+            "2060-08-26",
+            "2060-09-24",
+            "2060-10-24",
+            "2060-11-23",
+            "2060-12-23",
+            "2061-01-21",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16602,6 +19239,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2061,
         lunar_new_year: "2061-01-21",
         lichun_time: "2061-02-03 15:53:47",
+        leap_month: 3,
+        new_moons: &[
+            "2061-01-21",
+            "2061-02-20",
+            "2061-03-22",
+            "2061-04-20",
+            "2061-05-19",
+            "2061-06-18",
+            "2061-07-17",
+            "2061-08-15",
+            "2061-09-14",
+            "2061-10-13",
+            "2061-11-12",
+            "2061-12-12",
+            "2062-01-11",
+            "2062-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16705,6 +19359,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2062,
         lunar_new_year: "2062-02-09",
         lichun_time: "2062-02-03 21:47:05",
+        leap_month: 0,
+        new_moons: &[
+            "2062-02-09",
+            "2062-03-11",
+            "2062-04-10",
+            "2062-05-09",
+            "2062-06-07",
+            "2062-07-07",
+            "2062-08-05",
+            "2062-09-03",
+            "2062-10-03",
+            "2062-11-01",
+            "2062-12-01",
+            "2062-12-31",
+            "2063-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16808,6 +19478,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2063,
         lunar_new_year: "2063-01-29",
         lichun_time: "2063-02-04 03:31:12",
+        leap_month: 7,
+        new_moons: &[
+            "2063-01-29",
+            "2063-02-28",
+            "2063-03-30",
+            "2063-04-28",
+            "2063-05-28",
+            "2063-06-26",
+            "2063-07-26",
+            "2063-08-24",
+            "2063-09-22",
+            "2063-10-22",
+            "2063-11-20",
+            "2063-12-20",
+            "2064-01-18",
+            "2064-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -16911,6 +19598,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2064,
         lunar_new_year: "2064-02-17",
         lichun_time: "2064-02-04 09:14:52",
+        leap_month: 0,
+        new_moons: &[
+            "2064-02-17",
+            "2064-03-18",
+            "2064-04-17",
+            "2064-05-16",
+            "2064-06-15",
+            "2064-07-14",
+            "2064-08-13",
+            "2064-09-11",
+            "2064-10-10",
+            "2064-11-09",
+            "2064-12-08",
+            "2065-01-07",
+            "2065-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17014,6 +19717,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2065,
         lunar_new_year: "2065-02-05",
         lichun_time: "2065-02-03 15:03:41",
+        leap_month: 0,
+        new_moons: &[
+            "2065-02-05",
+            "2065-03-07",
+            "2065-04-06",
+            "2065-05-05",
+            "2065-06-04",
+            "2065-07-04",
+            "2065-08-02",
+            "2065-09-01",
+            "2065-09-30",
+            "2065-10-29",
+            "2065-11-28",
+            "2065-12-27",
+            "2066-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17117,6 +19836,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2066,
         lunar_new_year: "2066-01-26",
         lichun_time: "2066-02-03 20:49:22",
+        leap_month: 5,
+        new_moons: &[
+            "2066-01-26",
+            "2066-02-24",
+            "2066-03-26",
+            "2066-04-24",
+            "2066-05-24",
+            "2066-06-23",
+            "2066-07-22",
+            "2066-08-21",
+            "2066-09-19",
+            "2066-10-19",
+            "2066-11-17",
+            "2066-12-17",
+            "2067-01-15",
+            "2067-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17220,6 +19956,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2067,
         lunar_new_year: "2067-02-14",
         lichun_time: "2067-02-04 02:37:20",
+        leap_month: 0,
+        new_moons: &[
+            "2067-02-14",
+            "2067-03-15",
+            "2067-04-14",
+            "2067-05-13",
+            "2067-06-12",
+            "2067-07-11",
+            "2067-08-10",
+            "2067-09-09",
+            "2067-10-08",
+            "2067-11-07",
+            "2067-12-06",
+            "2068-01-05",
+            "2068-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17323,6 +20075,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2068,
         lunar_new_year: "2068-02-03",
         lichun_time: "2068-02-04 08:29:03",
+        leap_month: 0,
+        new_moons: &[
+            "2068-02-03",
+            "2068-03-04",
+            "2068-04-02",
+            "2068-05-02",
+            "2068-05-31",
+            "2068-06-29",
+            "2068-07-29",
+            "2068-08-28",
+            "2068-09-26",
+            "2068-10-26",
+            "2068-11-25",
+            "2068-12-24",
+            "2069-01-23",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17426,6 +20194,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2069,
         lunar_new_year: "2069-01-23",
         lichun_time: "2069-02-03 14:20:50",
+        leap_month: 4,
+        new_moons: &[
+            "2069-01-23",
+            "2069-02-21",
+            "2069-03-23",
+            "2069-04-21",
+            "2069-05-21",
+            "2069-06-19",
+            "2069-07-18",
+            "2069-08-17",
+            "2069-09-15",
+            "2069-10-15",
+            "2069-11-14",
+            "2069-12-14",
+            "2070-01-12",
+            "2070-02-11",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17529,6 +20314,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2070,
         lunar_new_year: "2070-02-11",
         lichun_time: "2070-02-03 20:21:48",
+        leap_month: 0,
+        new_moons: &[
+            "2070-02-11",
+            "2070-03-12",
+            "2070-04-11",
+            "2070-05-10",
+            "2070-06-09",
+            "2070-07-08",
+            "2070-08-06",
+            "2070-09-05",
+            "2070-10-04",
+            "2070-11-03",
+            "2070-12-03",
+            "2071-01-01",
+            "2071-01-31",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17632,6 +20433,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2071,
         lunar_new_year: "2071-01-31",
         lichun_time: "2071-02-04 02:10:50",
+        leap_month: 8,
+        new_moons: &[
+            "2071-01-31",
+            "2071-03-02",
+            "2071-03-31",
+            "2071-04-30",
+            "2071-05-29",
+            "2071-06-28",
+            "2071-07-27",
+            "2071-08-25",
+            "2071-09-24",
+            "2071-10-23",
+            "2071-11-22",
+            "2071-12-21",
+            "2072-01-20",
+            "2072-02-19",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17735,6 +20553,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2072,
         lunar_new_year: "2072-02-19",
         lichun_time: "2072-02-04 07:57:00",
+        leap_month: 0,
+        new_moons: &[
+            "2072-02-19",
+            "2072-03-20",
+            "2072-04-18",
+            "2072-05-18",
+            "2072-06-16",
+            "2072-07-16",
+            "2072-08-14",
+            "2072-09-12",
+            "2072-10-12",
+            "2072-11-10",
+            "2072-12-10",
+            "2073-01-08",
+            "2073-02-07",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17838,6 +20672,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2073,
         lunar_new_year: "2073-02-07",
         lichun_time: "2073-02-03 13:52:44",
+        leap_month: 0,
+        new_moons: &[
+            "2073-02-07",
+            "2073-03-09",
+            "2073-04-07",
+            "2073-05-07",
+            "2073-06-06",
+            "2073-07-05",
+            "2073-08-04",
+            "2073-09-02",
+            "2073-10-01",
+            "2073-10-31",
+            "2073-11-29",
+            "2073-12-29",
+            "2074-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -17941,6 +20791,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2074,
         lunar_new_year: "2074-01-27",
         lichun_time: "2074-02-03 19:41:15",
+        leap_month: 6,
+        new_moons: &[
+            "2074-01-27",
+            "2074-02-26",
+            "2074-03-27",
+            "2074-04-26",
+            "2074-05-26",
+            "2074-06-24",
+            "2074-07-24",
+            "2074-08-22",
+            "2074-09-21",
+            "2074-10-20",
+            "2074-11-19",
+            "2074-12-18",
+            "2075-01-17",
+            "2075-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18044,6 +20911,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2075,
         lunar_new_year: "2075-02-15",
         lichun_time: "2075-02-04 01:30:36",
+        leap_month: 0,
+        new_moons: &[
+            "2075-02-15",
+            "2075-03-17",
+            "2075-04-15",
+            "2075-05-15",
+            "2075-06-13",
+            "2075-07-13",
+            "2075-08-12",
+            "2075-09-10",
+            "2075-10-10",
+            "2075-11-08",
+            "2075-12-08",
+            "2076-01-06",
+            "2076-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18147,6 +21030,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2076,
         lunar_new_year: "2076-02-05",
         lichun_time: "2076-02-04 07:19:52",
+        leap_month: 0,
+        new_moons: &[
+            "2076-02-05",
+            "2076-03-05",
+            "2076-04-04",
+            "2076-05-03",
+            "2076-06-02",
+            "2076-07-01",
+            "2076-07-31",
+            "2076-08-29",
+            "2076-09-28",
+            "2076-10-28",
+            "2076-11-26",
+            "2076-12-26",
+            "2077-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18250,6 +21149,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2077,
         lunar_new_year: "2077-01-24",
         lichun_time: "2077-02-03 13:03:06",
+        leap_month: 4,
+        new_moons: &[
+            "2077-01-24",
+            "2077-02-23",
+            "2077-03-24",
+            "2077-04-23",
+            "2077-05-22",
+            "2077-06-20",
+            "2077-07-20",
+            "2077-08-18",
+            "2077-09-17",
+            "2077-10-17",
+            "2077-11-16",
+            "2077-12-15",
+            "2078-01-14",
+            "2078-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18353,6 +21269,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2078,
         lunar_new_year: "2078-02-12",
         lichun_time: "2078-02-03 18:57:21",
+        leap_month: 0,
+        new_moons: &[
+            "2078-02-12",
+            "2078-03-14",
+            "2078-04-12",
+            "2078-05-12",
+            "2078-06-10",
+            "2078-07-09",
+            "2078-08-08",
+            "2078-09-06",
+            "2078-10-06",
+            "2078-11-05",
+            "2078-12-04",
+            "2079-01-03",
+            "2079-02-02",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18456,6 +21388,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2079,
         lunar_new_year: "2079-02-02",
         lichun_time: "2079-02-04 00:43:11",
+        leap_month: 0,
+        new_moons: &[
+            "2079-02-02",
+            "2079-03-03",
+            "2079-04-02",
+            "2079-05-01",
+            "2079-05-31",
+            "2079-06-29",
+            "2079-07-28",
+            "2079-08-27",
+            "2079-09-25",
+            "2079-10-25",
+            "2079-11-23",
+            "2079-12-23",
+            "2080-01-22",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18559,6 +21507,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2080,
         lunar_new_year: "2080-01-22",
         lichun_time: "2080-02-04 06:27:57",
+        leap_month: 3,
+        new_moons: &[
+            "2080-01-22",
+            "2080-02-21",
+            "2080-03-21",
+            "2080-04-20",
+            "2080-05-19",
+            "2080-06-18",
+            "2080-07-17",
+            "2080-08-15",
+            "2080-09-14",
+            "2080-10-13",
+            "2080-11-11",
+            "2080-12-11",
+            "2081-01-10",
+            "2081-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18662,6 +21627,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2081,
         lunar_new_year: "2081-02-09",
         lichun_time: "2081-02-03 12:25:55",
+        leap_month: 0,
+        new_moons: &[
+            "2081-02-09",
+            "2081-03-10",
+            "2081-04-09",
+            "2081-05-09",
+            "2081-06-07",
+            "2081-07-07",
+            "2081-08-05",
+            "2081-09-03",
+            "2081-10-03",
+            "2081-11-01",
+            "2081-11-30",
+            "2081-12-30",
+            "2082-01-29",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18765,6 +21746,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2082,
         lunar_new_year: "2082-01-29",
         lichun_time: "2082-02-03 18:12:12",
+        leap_month: 7,
+        new_moons: &[
+            "2082-01-29",
+            "2082-02-27",
+            "2082-03-29",
+            "2082-04-28",
+            "2082-05-28",
+            "2082-06-26",
+            "2082-07-25",
+            "2082-08-24",
+            "2082-09-22",
+            "2082-10-22",
+            "2082-11-20",
+            "2082-12-19",
+            "2083-01-18",
+            "2083-02-17",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18868,6 +21866,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2083,
         lunar_new_year: "2083-02-17",
         lichun_time: "2083-02-03 23:58:22",
+        leap_month: 0,
+        new_moons: &[
+            "2083-02-17",
+            "2083-03-18",
+            "2083-04-17",
+            "2083-05-17",
+            "2083-06-15",
+            "2083-07-15",
+            "2083-08-13",
+            "2083-09-12",
+            "2083-10-11",
+            "2083-11-10",
+            "2083-12-09",
+            "2084-01-08",
+            "2084-02-06",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -18971,6 +21985,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2084,
         lunar_new_year: "2084-02-06",
         lichun_time: "2084-02-04 05:46:37",
+        leap_month: 0,
+        new_moons: &[
+            "2084-02-06",
+            "2084-03-07",
+            "2084-04-05",
+            "2084-05-05",
+            "2084-06-03",
+            "2084-07-03",
+            "2084-08-02",
+            "2084-08-31",
+            "2084-09-30",
+            "2084-10-29",
+            "2084-11-28",
+            "2084-12-27",
+            "2085-01-26",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19074,6 +22104,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2085,
         lunar_new_year: "2085-01-26",
         lichun_time: "2085-02-03 11:29:53",
+        leap_month: 5,
+        new_moons: &[
+            "2085-01-26",
+            "2085-02-24",
+            "2085-03-26",
+            "2085-04-24",
+            "2085-05-23",
+            "2085-06-22",
+            "2085-07-22",
+            "2085-08-20",
+            "2085-09-19",
+            "2085-10-19",
+            "2085-11-17",
+            "2085-12-17",
+            "2086-01-15",
+            "2086-02-14",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19177,6 +22224,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2086,
         lunar_new_year: "2086-02-14",
         lichun_time: "2086-02-03 17:26:26",
+        leap_month: 0,
+        new_moons: &[
+            "2086-02-14",
+            "2086-03-15",
+            "2086-04-14",
+            "2086-05-13",
+            "2086-06-11",
+            "2086-07-11",
+            "2086-08-09",
+            "2086-09-08",
+            "2086-10-08",
+            "2086-11-06",
+            "2086-12-06",
+            "2087-01-05",
+            "2087-02-03",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19280,6 +22343,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2087,
         lunar_new_year: "2087-02-03",
         lichun_time: "2087-02-03 23:15:12",
+        leap_month: 0,
+        new_moons: &[
+            "2087-02-03",
+            "2087-03-05",
+            "2087-04-03",
+            "2087-05-03",
+            "2087-06-01",
+            "2087-06-30",
+            "2087-07-30",
+            "2087-08-28",
+            "2087-09-27",
+            "2087-10-26",
+            "2087-11-25",
+            "2087-12-25",
+            "2088-01-24",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19383,6 +22462,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2088,
         lunar_new_year: "2088-01-24",
         lichun_time: "2088-02-04 04:58:09",
+        leap_month: 4,
+        new_moons: &[
+            "2088-01-24",
+            "2088-02-22",
+            "2088-03-23",
+            "2088-04-21",
+            "2088-05-21",
+            "2088-06-19",
+            "2088-07-18",
+            "2088-08-17",
+            "2088-09-15",
+            "2088-10-14",
+            "2088-11-13",
+            "2088-12-13",
+            "2089-01-12",
+            "2089-02-10",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19486,6 +22582,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2089,
         lunar_new_year: "2089-02-10",
         lichun_time: "2089-02-03 10:54:39",
+        leap_month: 0,
+        new_moons: &[
+            "2089-02-10",
+            "2089-03-12",
+            "2089-04-11",
+            "2089-05-10",
+            "2089-06-09",
+            "2089-07-08",
+            "2089-08-06",
+            "2089-09-05",
+            "2089-10-04",
+            "2089-11-02",
+            "2089-12-02",
+            "2090-01-01",
+            "2090-01-30",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19589,6 +22701,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2090,
         lunar_new_year: "2090-01-30",
         lichun_time: "2090-02-03 16:42:17",
+        leap_month: 8,
+        new_moons: &[
+            "2090-01-30",
+            "2090-03-01",
+            "2090-03-31",
+            "2090-04-30",
+            "2090-05-29",
+            "2090-06-28",
+            "2090-07-27",
+            "2090-08-25",
+            "2090-09-24",
+            "2090-10-23",
+            "2090-11-21",
+            "2090-12-21",
+            "2091-01-20",
+            "2091-02-18",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19692,6 +22821,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2091,
         lunar_new_year: "2091-02-18",
         lichun_time: "2091-02-03 22:30:49",
+        leap_month: 0,
+        new_moons: &[
+            "2091-02-18",
+            "2091-03-20",
+            "2091-04-19",
+            "2091-05-18",
+            "2091-06-17",
+            "2091-07-16",
+            "2091-08-15",
+            "2091-09-13",
+            "2091-10-13",
+            "2091-11-11",
+            "2091-12-10",
+            "2092-01-09",
+            "2092-02-07",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19795,6 +22940,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2092,
         lunar_new_year: "2092-02-07",
         lichun_time: "2092-02-04 04:28:49",
+        leap_month: 0,
+        new_moons: &[
+            "2092-02-07",
+            "2092-03-08",
+            "2092-04-07",
+            "2092-05-06",
+            "2092-06-05",
+            "2092-07-05",
+            "2092-08-03",
+            "2092-09-02",
+            "2092-10-01",
+            "2092-10-31",
+            "2092-11-29",
+            "2092-12-29",
+            "2093-01-27",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -19898,6 +23059,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2093,
         lunar_new_year: "2093-01-27",
         lichun_time: "2093-02-03 10:18:35",
+        leap_month: 6,
+        new_moons: &[
+            "2093-01-27",
+            "2093-02-25",
+            "2093-03-27",
+            "2093-04-26",
+            "2093-05-25",
+            "2093-06-24",
+            "2093-07-23",
+            "2093-08-22",
+            "2093-09-21",
+            "2093-10-20",
+            "2093-11-19",
+            "2093-12-18",
+            "2094-01-17",
+            "2094-02-15",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20001,6 +23179,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2094,
         lunar_new_year: "2094-02-15",
         lichun_time: "2094-02-03 16:17:03",
+        leap_month: 0,
+        new_moons: &[
+            "2094-02-15",
+            "2094-03-16",
+            "2094-04-15",
+            "2094-05-14",
+            "2094-06-13",
+            "2094-07-12",
+            "2094-08-11",
+            "2094-09-10",
+            "2094-10-09",
+            "2094-11-08",
+            "2094-12-08",
+            "2095-01-06",
+            "2095-02-05",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20104,6 +23298,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2095,
         lunar_new_year: "2095-02-05",
         lichun_time: "2095-02-03 22:07:04",
+        leap_month: 0,
+        new_moons: &[
+            "2095-02-05",
+            "2095-03-06",
+            "2095-04-05",
+            "2095-05-04",
+            "2095-06-02",
+            "2095-07-02",
+            "2095-07-31",
+            "2095-08-30",
+            "2095-09-28",
+            "2095-10-28",
+            "2095-11-27",
+            "2095-12-27",
+            "2096-01-25",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20207,6 +23417,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2096,
         lunar_new_year: "2096-01-25",
         lichun_time: "2096-02-04 03:46:51",
+        leap_month: 4,
+        new_moons: &[
+            "2096-01-25",
+            "2096-02-24",
+            "2096-03-24",
+            "2096-04-23",
+            "2096-05-22",
+            "2096-06-20",
+            "2096-07-20",
+            "2096-08-18",
+            "2096-09-16",
+            "2096-10-16",
+            "2096-11-15",
+            "2096-12-15",
+            "2097-01-13",
+            "2097-02-12",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20310,6 +23537,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2097,
         lunar_new_year: "2097-02-12",
         lichun_time: "2097-02-03 09:41:59",
+        leap_month: 0,
+        new_moons: &[
+            "2097-02-12",
+            "2097-03-14",
+            "2097-04-12",
+            "2097-05-12",
+            "2097-06-10",
+            "2097-07-09",
+            "2097-08-08",
+            "2097-09-06",
+            "2097-10-05",
+            "2097-11-04",
+            "2097-12-04",
+            "2098-01-02",
+            "2098-02-01",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20413,6 +23656,22 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2098,
         lunar_new_year: "2098-02-01",
         lichun_time: "2098-02-03 15:29:00",
+        leap_month: 0,
+        new_moons: &[
+            "2098-02-01",
+            "2098-03-03",
+            "2098-04-02",
+            "2098-05-01",
+            "2098-05-31",
+            "2098-06-29",
+            "2098-07-28",
+            "2098-08-26",
+            "2098-09-25",
+            "2098-10-24",
+            "2098-11-23",
+            "2098-12-22",
+            "2099-01-21",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -20516,6 +23775,23 @@ pub const BAZI_YEAR_DATA: [BaziYearData; 200] = [
         year: 2099,
         lunar_new_year: "2099-01-21",
         lichun_time: "2099-02-03 21:09:29",
+        leap_month: 2,
+        new_moons: &[
+            "2099-01-21",
+            "2099-02-20",
+            "2099-03-22",
+            "2099-04-20",
+            "2099-05-20",
+            "2099-06-19",
+            "2099-07-18",
+            "2099-08-16",
+            "2099-09-15",
+            "2099-10-14",
+            "2099-11-12",
+            "2099-12-12",
+            "2100-01-10",
+            "2100-02-09",
+        ],
         jieqi: [
             JieQiData {
                 name: "大雪",
@@ -0,0 +1,363 @@
+use crate::data::BAZI_YEAR_DATA;
+use crate::error::LunarError;
+use crate::table_lookup::{MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR, table_index};
+use chrono::{Datelike, NaiveDate};
+use std::sync::OnceLock;
+
+const MONTH_NAMES: [&str; 12] = [
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊",
+];
+const DAY_TENS: [&str; 4] = ["初", "十", "廿", "三"];
+const DAY_UNITS: [&str; 10] = ["十", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+/// A date in the Chinese lunisolar calendar (农历).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LunarDate {
+    /// Lunar year, numbered by the Gregorian year in which its 正月初一 falls.
+    pub year: i32,
+    /// Lunar month, 1..=12.
+    pub month: u8,
+    /// Day of the lunar month, 1..=30.
+    pub day: u8,
+    /// Whether `month` is the leap month (闰月) of the year.
+    pub is_leap: bool,
+}
+
+impl std::fmt::Display for LunarDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let leap = if self.is_leap { "闰" } else { "" };
+        let month = MONTH_NAMES[(self.month as usize - 1) % 12];
+        let day = match self.day {
+            10 => "初十".to_string(),
+            20 => "二十".to_string(),
+            30 => "三十".to_string(),
+            d => format!(
+                "{}{}",
+                DAY_TENS[(d / 10) as usize % 4],
+                DAY_UNITS[(d % 10) as usize]
+            ),
+        };
+        write!(f, "{}年{}{}月{}", self.year, leap, month, day)
+    }
+}
+
+struct ParsedLunarYear {
+    year: i32,
+    leap_month: u8,
+    new_moons: Vec<NaiveDate>,
+}
+
+static PARSED_LUNAR_TABLE: OnceLock<Result<Vec<ParsedLunarYear>, LunarError>> = OnceLock::new();
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn parsed_lunar_table() -> Result<&'static [ParsedLunarYear], LunarError> {
+    PARSED_LUNAR_TABLE
+        .get_or_init(|| {
+            let mut parsed = Vec::with_capacity(BAZI_YEAR_DATA.len());
+            for row in BAZI_YEAR_DATA.iter() {
+                let mut new_moons = Vec::with_capacity(row.new_moons.len());
+                for day in row.new_moons.iter() {
+                    new_moons.push(parse_date(day).ok_or(LunarError::CorruptTable {
+                        year: row.year,
+                        field: "new_moons",
+                    })?);
+                }
+                parsed.push(ParsedLunarYear {
+                    year: row.year,
+                    leap_month: row.leap_month,
+                    new_moons,
+                });
+            }
+            Ok(parsed)
+        })
+        .as_deref()
+        .map_err(Clone::clone)
+}
+
+fn parsed_lunar_year(year: i32) -> Result<&'static ParsedLunarYear, LunarError> {
+    let row = &parsed_lunar_table()?[table_index(year)?];
+    debug_assert_eq!(row.year, year);
+    Ok(row)
+}
+
+/// Returns the first day and length (29 or 30) of a lunar month.
+fn month_bounds(year: i32, month: u8, is_leap: bool) -> Result<(NaiveDate, u8), LunarError> {
+    let row = parsed_lunar_year(year)?;
+    if !(1..=12).contains(&month) {
+        return Err(LunarError::InvalidLunarMonth { month });
+    }
+    if is_leap && month != row.leap_month {
+        return Err(LunarError::NoLeapMonth { year, month });
+    }
+    let after_leap = row.leap_month != 0 && (month > row.leap_month || is_leap);
+    let slot = month as usize - 1 + usize::from(after_leap);
+    let corrupt = LunarError::CorruptTable {
+        year,
+        field: "new_moons",
+    };
+    let start = *row.new_moons.get(slot).ok_or(corrupt.clone())?;
+    let end = *row.new_moons.get(slot + 1).ok_or(corrupt)?;
+    Ok((start, (end - start).num_days() as u8))
+}
+
+/// Returns the number of days (29 or 30) in a lunar month.
+///
+/// `None` means the year is outside the table or the month does not exist,
+/// e.g. a leap month that the year does not have.
+pub fn lunar_month_days(year: i32, month: u8, is_leap: bool) -> Option<u8> {
+    month_bounds(year, month, is_leap)
+        .ok()
+        .map(|(_, days)| days)
+}
+
+/// Converts a lunar date back to its Gregorian date.
+///
+/// Returns `None` for days that do not exist, such as the 30th of a 29-day
+/// month or a leap month the year does not have.
+pub fn from_lunar_date(year: i32, month: u8, day: u8, is_leap: bool) -> Option<NaiveDate> {
+    try_from_lunar_date(year, month, day, is_leap).ok()
+}
+
+/// Like [`from_lunar_date`], but explains why the lunar date does not exist.
+pub fn try_from_lunar_date(
+    year: i32,
+    month: u8,
+    day: u8,
+    is_leap: bool,
+) -> Result<NaiveDate, LunarError> {
+    let (start, days_in_month) = month_bounds(year, month, is_leap)?;
+    if !(1..=days_in_month).contains(&day) {
+        return Err(LunarError::InvalidLunarDay {
+            year,
+            month,
+            is_leap,
+            day,
+            days_in_month,
+        });
+    }
+    Ok(start + chrono::Days::new(u64::from(day) - 1))
+}
+
+/// Converts a Gregorian date to a lunar date using the `new_moons` column of `BAZI_YEAR_DATA`.
+///
+/// Covers 1900-01-31 (lunar 1900 正月初一) through the last day of lunar year 2099.
+pub fn to_lunar_date(date: NaiveDate) -> Option<LunarDate> {
+    try_to_lunar_date(date).ok()
+}
+
+/// Like [`to_lunar_date`], but explains why the date cannot be converted.
+pub fn try_to_lunar_date(date: NaiveDate) -> Result<LunarDate, LunarError> {
+    let starts_on_or_before = |year: i32| {
+        parsed_lunar_year(year)
+            .ok()
+            .and_then(|row| row.new_moons.first())
+            .is_some_and(|lny| *lny <= date)
+    };
+    let lunar_year = if starts_on_or_before(date.year()) {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+
+    let row = parsed_lunar_year(lunar_year)?;
+    let (slot, start) = row
+        .new_moons
+        .windows(2)
+        .enumerate()
+        .find(|(_, pair)| pair[0] <= date && date < pair[1])
+        .map(|(i, pair)| (i, pair[0]))
+        .ok_or(LunarError::OutOfRange {
+            year: date.year(),
+            min: MIN_SUPPORTED_YEAR,
+            max: MAX_SUPPORTED_YEAR,
+        })?;
+
+    // Slots run 正月..腊月 with the leap month inserted right after its namesake.
+    let slot = slot as u8;
+    let leap = row.leap_month;
+    let (month, is_leap) = if leap == 0 || slot < leap {
+        (slot + 1, false)
+    } else if slot == leap {
+        (leap, true)
+    } else {
+        (slot, false)
+    };
+
+    Ok(LunarDate {
+        year: lunar_year,
+        month,
+        day: (date - start).num_days() as u8 + 1,
+        is_leap,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn lunar(year: i32, month: u8, day: u8, is_leap: bool) -> LunarDate {
+        LunarDate {
+            year,
+            month,
+            day,
+            is_leap,
+        }
+    }
+
+    #[test]
+    fn known_lunar_dates() {
+        let cases = [
+            (ymd(1985, 4, 21), lunar(1985, 3, 2, false)),
+            (ymd(1985, 2, 20), lunar(1985, 1, 1, false)),
+            (ymd(1985, 2, 19), lunar(1984, 12, 30, false)),
+            (ymd(2024, 2, 10), lunar(2024, 1, 1, false)),
+            (ymd(2026, 2, 4), lunar(2025, 12, 17, false)),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(to_lunar_date(date), Some(expected), "failed for {date}");
+        }
+    }
+
+    #[test]
+    fn leap_month_dates() {
+        // 2023 has 闰二月 starting 2023-03-22.
+        assert_eq!(
+            to_lunar_date(ymd(2023, 3, 21)),
+            Some(lunar(2023, 2, 30, false))
+        );
+        assert_eq!(
+            to_lunar_date(ymd(2023, 3, 22)),
+            Some(lunar(2023, 2, 1, true))
+        );
+        assert_eq!(
+            to_lunar_date(ymd(2023, 4, 20)),
+            Some(lunar(2023, 3, 1, false))
+        );
+        // 1900 has 闰八月.
+        assert_eq!(
+            to_lunar_date(ymd(1900, 9, 24)),
+            Some(lunar(1900, 8, 1, true))
+        );
+    }
+
+    #[test]
+    fn lunar_date_range_is_bounded() {
+        assert!(to_lunar_date(ymd(1900, 1, 30)).is_none());
+        assert_eq!(
+            to_lunar_date(ymd(1900, 1, 31)),
+            Some(lunar(1900, 1, 1, false))
+        );
+        assert_eq!(to_lunar_date(ymd(2100, 2, 8)).map(|d| d.year), Some(2099));
+        assert!(to_lunar_date(ymd(2100, 2, 9)).is_none());
+    }
+
+    #[test]
+    fn known_gregorian_dates_from_lunar() {
+        assert_eq!(from_lunar_date(1985, 3, 2, false), Some(ymd(1985, 4, 21)));
+        assert_eq!(from_lunar_date(1984, 12, 30, false), Some(ymd(1985, 2, 19)));
+        assert_eq!(from_lunar_date(2023, 2, 1, true), Some(ymd(2023, 3, 22)));
+        assert_eq!(from_lunar_date(1900, 8, 29, true), Some(ymd(1900, 10, 22)));
+        assert_eq!(from_lunar_date(2099, 12, 30, false), Some(ymd(2100, 2, 8)));
+    }
+
+    #[test]
+    fn non_existent_lunar_dates() {
+        // 2023 闰二月 has 29 days.
+        assert_eq!(lunar_month_days(2023, 2, true), Some(29));
+        assert!(from_lunar_date(2023, 2, 30, true).is_none());
+        // 1985 has no leap month at all, 2023 has none besides 二月.
+        assert!(from_lunar_date(1985, 3, 2, true).is_none());
+        assert!(lunar_month_days(2023, 6, true).is_none());
+        assert!(from_lunar_date(1985, 13, 1, false).is_none());
+        assert!(from_lunar_date(1985, 1, 0, false).is_none());
+        assert!(from_lunar_date(1899, 1, 1, false).is_none());
+        assert!(from_lunar_date(2100, 1, 1, false).is_none());
+    }
+
+    #[test]
+    fn lunar_errors_explain_missing_days() {
+        assert_eq!(
+            try_from_lunar_date(2023, 2, 30, true),
+            Err(LunarError::InvalidLunarDay {
+                year: 2023,
+                month: 2,
+                is_leap: true,
+                day: 30,
+                days_in_month: 29
+            })
+        );
+        assert_eq!(
+            try_from_lunar_date(1985, 3, 2, true),
+            Err(LunarError::NoLeapMonth {
+                year: 1985,
+                month: 3
+            })
+        );
+        assert_eq!(
+            try_from_lunar_date(1985, 13, 1, false),
+            Err(LunarError::InvalidLunarMonth { month: 13 })
+        );
+        assert_eq!(
+            try_to_lunar_date(ymd(1900, 1, 30)),
+            Err(LunarError::OutOfRange {
+                year: 1899,
+                min: 1900,
+                max: 2099
+            })
+        );
+    }
+
+    #[test]
+    fn lunar_round_trip_covers_table() {
+        let mut date = ymd(1900, 1, 31);
+        while date < ymd(2100, 2, 9) {
+            let lunar = to_lunar_date(date).unwrap();
+            assert_eq!(
+                from_lunar_date(lunar.year, lunar.month, lunar.day, lunar.is_leap),
+                Some(date),
+                "failed for {date}"
+            );
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn cross_check_with_external_crate() {
+        use chinese_lunisolar_calendar::LunisolarDate;
+
+        // The external crate covers lunar years 1901..=2100.
+        let mut date = ymd(1901, 2, 19);
+        while date <= ymd(2099, 12, 31) {
+            let ours = to_lunar_date(date).unwrap();
+            let theirs = LunisolarDate::from_date(date).unwrap();
+            let month = theirs.to_lunar_month();
+            assert_eq!(
+                (ours.year, ours.month, ours.day, ours.is_leap),
+                (
+                    theirs.to_lunisolar_year().to_u16() as i32,
+                    month.to_u8(),
+                    theirs.to_lunar_day().to_u8(),
+                    month.is_leap_month()
+                ),
+                "failed for {date}"
+            );
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn lunar_date_display() {
+        assert_eq!(lunar(1985, 3, 2, false).to_string(), "1985年三月初二");
+        assert_eq!(lunar(2023, 2, 20, true).to_string(), "2023年闰二月二十");
+        assert_eq!(lunar(1984, 12, 30, false).to_string(), "1984年腊月三十");
+        assert_eq!(lunar(2024, 11, 21, false).to_string(), "2024年冬月廿一");
+    }
+}
@@ -1,11 +1,33 @@
 use chrono::NaiveDateTime;
 use lunar_calendar::*;
+use std::env;
+use std::io;
 
 fn parse_builtin_datetime(input: &str) -> Option<NaiveDateTime> {
     NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M:%S").ok()
 }
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => print_demo(),
+        ["serve", "--stdio"] => {
+            if let Err(e) = serve(io::stdin().lock(), io::stdout().lock()) {
+                eprintln!("serve: {e}");
+                std::process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!(
+                "Usage: lunar-calendar | lunar-calendar serve --stdio (line-delimited JSON-RPC 2.0; methods: {})",
+                RPC_METHODS.join(", ")
+            );
+            std::process::exit(2);
+        }
+    }
+}
+
+fn print_demo() {
     println!("--- 60甲子纳音五行对照表 ---");
     println!("{:<4} {:<4} {:<8}", "序号", "干支", "纳音五行");
 
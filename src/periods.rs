@@ -0,0 +1,277 @@
+use crate::bazi_calc::{day_pillar, term_time};
+use crate::error::LunarError;
+use crate::ganzhi::HeavenlyStem;
+use chrono::{Days, NaiveDate};
+use std::fmt;
+
+/// An inclusive run of civil dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatePeriod {
+    pub start: NaiveDate,
+    /// Last day of the period (inclusive).
+    pub end: NaiveDate,
+}
+
+impl DatePeriod {
+    fn from_start(start: NaiveDate, days: u64) -> DatePeriod {
+        DatePeriod {
+            start,
+            end: start + Days::new(days - 1),
+        }
+    }
+
+    /// Number of days, counting both ends.
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        (self.start..=self.end).contains(&date)
+    }
+}
+
+impl fmt::Display for DatePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} → {}", self.start, self.end)
+    }
+}
+
+/// The three 伏 (三伏天) of a summer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SanFu {
+    pub year: i32,
+    /// 初伏: ten days from the third 庚 day counted from 夏至.
+    pub chu: DatePeriod,
+    /// 中伏: from the fourth 庚 day until 末伏, ten or twenty days.
+    pub zhong: DatePeriod,
+    /// 末伏: ten days from the first 庚 day counted from 立秋.
+    pub mo: DatePeriod,
+}
+
+impl SanFu {
+    pub fn start(&self) -> NaiveDate {
+        self.chu.start
+    }
+
+    /// Last day of 末伏; 出伏 is the day after.
+    pub fn end(&self) -> NaiveDate {
+        self.mo.end
+    }
+
+    /// 30 or 40.
+    pub fn days(&self) -> i64 {
+        (self.end() - self.start()).num_days() + 1
+    }
+}
+
+impl fmt::Display for SanFu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "初伏 {} | 中伏 {} | 末伏 {} ({}天)",
+            self.chu,
+            self.zhong,
+            self.mo,
+            self.days()
+        )
+    }
+}
+
+/// The nine nines (数九) of a winter, starting on 冬至.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShuJiu {
+    /// Year of the 冬至 that starts the count.
+    pub year: i32,
+    /// 一九 ... 九九, nine days each.
+    pub nines: [DatePeriod; 9],
+}
+
+impl ShuJiu {
+    pub fn start(&self) -> NaiveDate {
+        self.nines[0].start
+    }
+
+    /// Last day of 九九 (the 81st day, commonly given as 出九).
+    pub fn end(&self) -> NaiveDate {
+        self.nines[8].end
+    }
+
+    /// Which nine (1..=9) `date` falls in.
+    pub fn nine_of(&self, date: NaiveDate) -> Option<u8> {
+        (1..=9)
+            .zip(self.nines)
+            .find(|(_, period)| period.contains(date))
+            .map(|(n, _)| n)
+    }
+}
+
+impl fmt::Display for ShuJiu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "一九 {} | 三九 {} | 九九 {}",
+            self.nines[0], self.nines[2], self.nines[8]
+        )
+    }
+}
+
+/// The `n`-th (1-based) 庚 day on or after `from`.
+fn nth_geng_day(from: NaiveDate, n: u64) -> NaiveDate {
+    let stem = day_pillar(from).stem().index();
+    let to_first = (HeavenlyStem::Geng.index() + 10 - stem) % 10;
+    from + Days::new(to_first as u64 + 10 * (n - 1))
+}
+
+/// 三伏 of `year`, or `None` outside the table.
+pub fn sanfu(year: i32) -> Option<SanFu> {
+    try_sanfu(year).ok()
+}
+
+/// Like [`sanfu`], but explains why the year is unavailable.
+///
+/// 夏至 and 立秋 count themselves when they fall on a 庚 day; both are taken
+/// as UTC+8 dates from the JieQi table.
+pub fn try_sanfu(year: i32) -> Result<SanFu, LunarError> {
+    let summer = term_time(year, "夏至")?.date();
+    let autumn = term_time(year, "立秋")?.date();
+    let zhong_start = nth_geng_day(summer, 4);
+    let mo = DatePeriod::from_start(nth_geng_day(autumn, 1), 10);
+    Ok(SanFu {
+        year,
+        chu: DatePeriod::from_start(nth_geng_day(summer, 3), 10),
+        zhong: DatePeriod {
+            start: zhong_start,
+            end: mo.start - Days::new(1),
+        },
+        mo,
+    })
+}
+
+/// 数九 starting on the 冬至 of `year`, or `None` outside the table.
+pub fn shujiu(year: i32) -> Option<ShuJiu> {
+    try_shujiu(year).ok()
+}
+
+/// Like [`shujiu`], but explains why the year is unavailable.
+///
+/// 冬至 of a year sits in the next table row, so 1899 is available and 2099
+/// is out of range.
+pub fn try_shujiu(year: i32) -> Result<ShuJiu, LunarError> {
+    let solstice = term_time(year, "冬至")?.date();
+    Ok(ShuJiu {
+        year,
+        nines: std::array::from_fn(|i| {
+            DatePeriod::from_start(solstice + Days::new(9 * i as u64), 9)
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table_lookup::{MAX_SUPPORTED_YEAR, MIN_SUPPORTED_YEAR};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn sanfu_published_dates() {
+        // (year, 初伏, 中伏, 末伏, last day, total days)
+        let published = [
+            (2018, (7, 17), (7, 27), (8, 16), (8, 25), 40),
+            (2019, (7, 12), (7, 22), (8, 11), (8, 20), 40),
+            (2020, (7, 16), (7, 26), (8, 15), (8, 24), 40),
+            (2021, (7, 11), (7, 21), (8, 10), (8, 19), 40),
+            (2022, (7, 16), (7, 26), (8, 15), (8, 24), 40),
+            (2023, (7, 11), (7, 21), (8, 10), (8, 19), 40),
+            (2024, (7, 15), (7, 25), (8, 14), (8, 23), 40),
+            (2025, (7, 20), (7, 30), (8, 9), (8, 18), 30),
+        ];
+        for (year, chu, zhong, mo, end, days) in published {
+            let fu = sanfu(year).unwrap();
+            assert_eq!(fu.chu.start, date(year, chu.0, chu.1), "{year}");
+            assert_eq!(fu.zhong.start, date(year, zhong.0, zhong.1), "{year}");
+            assert_eq!(fu.mo.start, date(year, mo.0, mo.1), "{year}");
+            assert_eq!(fu.end(), date(year, end.0, end.1), "{year}");
+            assert_eq!(fu.days(), days, "{year}");
+        }
+    }
+
+    #[test]
+    fn shujiu_published_dates() {
+        // (冬至 year, 冬至, last day of 九九)
+        let published = [
+            (2021, date(2021, 12, 21), date(2022, 3, 11)),
+            (2022, date(2022, 12, 22), date(2023, 3, 12)),
+            (2023, date(2023, 12, 22), date(2024, 3, 11)),
+            (2024, date(2024, 12, 21), date(2025, 3, 11)),
+        ];
+        for (year, start, end) in published {
+            let nines = shujiu(year).unwrap();
+            assert_eq!(nines.start(), start, "{year}");
+            assert_eq!(nines.end(), end, "{year}");
+        }
+        let winter = shujiu(2024).unwrap();
+        assert_eq!(winter.nines[2].start, date(2025, 1, 8));
+        assert_eq!(winter.nine_of(date(2025, 1, 10)), Some(3));
+        assert_eq!(winter.nine_of(date(2025, 3, 12)), None);
+    }
+
+    #[test]
+    fn every_supported_year_follows_the_rules() {
+        for year in MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR {
+            let fu = sanfu(year).unwrap();
+            let summer = term_time(year, "夏至").unwrap().date();
+            let autumn = term_time(year, "立秋").unwrap().date();
+            for start in [fu.chu.start, fu.zhong.start, fu.mo.start] {
+                assert_eq!(day_pillar(start).stem(), HeavenlyStem::Geng, "{year}");
+            }
+            assert!(
+                (20..30).contains(&(fu.chu.start - summer).num_days()),
+                "{year}"
+            );
+            assert!(
+                (0..10).contains(&(fu.mo.start - autumn).num_days()),
+                "{year}"
+            );
+            assert_eq!(fu.chu.days(), 10);
+            assert!(matches!(fu.zhong.days(), 10 | 20), "{year}");
+            assert_eq!(fu.mo.days(), 10);
+            assert!(matches!(fu.days(), 30 | 40), "{year}");
+        }
+        for year in MIN_SUPPORTED_YEAR - 1..MAX_SUPPORTED_YEAR {
+            let nines = shujiu(year).unwrap();
+            assert_eq!((nines.end() - nines.start()).num_days(), 80);
+            for pair in nines.nines.windows(2) {
+                assert_eq!(pair[0].end.succ_opt(), Some(pair[1].start));
+            }
+        }
+    }
+
+    #[test]
+    fn table_edges() {
+        assert_eq!(shujiu(1899).unwrap().start(), date(1899, 12, 22));
+        assert!(matches!(
+            try_shujiu(MAX_SUPPORTED_YEAR),
+            Err(LunarError::OutOfRange { year: 2100, .. })
+        ));
+        assert!(matches!(
+            try_sanfu(MAX_SUPPORTED_YEAR + 1),
+            Err(LunarError::OutOfRange { .. })
+        ));
+        for year in [i32::MIN, i32::MAX] {
+            assert!(matches!(
+                try_sanfu(year),
+                Err(LunarError::OutOfRange { .. })
+            ));
+            assert!(matches!(
+                try_shujiu(year),
+                Err(LunarError::OutOfRange { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,552 @@
+use crate::bazi_calc::{
+    BaziOptions, BaziPillars, JieQiContext, TABLE_UTC_OFFSET_SECONDS, ZiHourRule,
+    try_get_jieqi_context_tz, try_get_pillars_with,
+};
+use crate::data::BaziYearData;
+use crate::error::LunarError;
+use crate::ganzhi::GanZhi;
+use crate::lunar_date::{LunarDate, try_to_lunar_date};
+use crate::solar_time::SolarTimeCorrection;
+use crate::table_lookup::{get_cycle_index, try_get_year_data};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+use serde_json::{Map, Value, json};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// Methods answered by [`rpc_call`] and [`serve`].
+pub const RPC_METHODS: [&str; 5] = [
+    "pillars",
+    "jieqi_context",
+    "year_data",
+    "lunar_date",
+    "nayin",
+];
+
+/// A JSON-RPC 2.0 error object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    /// The request was valid but the table cannot answer it (a [`LunarError`]).
+    pub const UNAVAILABLE: i64 = -32000;
+
+    fn new(code: i64, message: impl Into<String>) -> RpcError {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> RpcError {
+        RpcError::new(RpcError::INVALID_PARAMS, message)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({ "code": self.code, "message": self.message })
+    }
+}
+
+impl From<LunarError> for RpcError {
+    fn from(e: LunarError) -> RpcError {
+        RpcError::new(RpcError::UNAVAILABLE, e.to_string())
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+fn table_zone() -> FixedOffset {
+    FixedOffset::east_opt(TABLE_UTC_OFFSET_SECONDS).expect("valid UTC+8 offset")
+}
+
+/// Table time (UTC+8) as RFC 3339, e.g. `2026-02-04T04:02:08+08:00`.
+pub fn table_rfc3339(time: NaiveDateTime) -> String {
+    table_zone()
+        .from_local_datetime(&time)
+        .unwrap()
+        .to_rfc3339()
+}
+
+fn table_time_str(value: &str) -> Option<String> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(table_rfc3339)
+}
+
+fn str_param<'a>(params: &'a Map<String, Value>, name: &str) -> Result<Option<&'a str>, RpcError> {
+    match params.get(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(_) => Err(RpcError::invalid_params(format!(
+            "`{name}` must be a string"
+        ))),
+    }
+}
+
+fn required_str<'a>(params: &'a Map<String, Value>, name: &str) -> Result<&'a str, RpcError> {
+    str_param(params, name)?.ok_or_else(|| RpcError::invalid_params(format!("missing `{name}`")))
+}
+
+fn year_param(params: &Map<String, Value>) -> Result<Option<i32>, RpcError> {
+    match params.get("year") {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_i64()
+            .and_then(|y| i32::try_from(y).ok())
+            .map(Some)
+            .ok_or_else(|| RpcError::invalid_params("`year` must be an integer")),
+    }
+}
+
+/// `YYYY-MM-DD[ HH:MM[:SS]]` (read as UTC+8) or RFC 3339 with an offset.
+fn parse_datetime(value: &str) -> Result<DateTime<FixedOffset>, RpcError> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt);
+    }
+    let naive = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+    })
+    .ok_or_else(|| {
+        RpcError::invalid_params(format!(
+            "invalid datetime '{value}': use YYYY-MM-DD[ HH:MM[:SS]] (UTC+8) or RFC 3339"
+        ))
+    })?;
+    Ok(table_zone().from_local_datetime(&naive).unwrap())
+}
+
+fn bazi_options(params: &Map<String, Value>) -> Result<BaziOptions, RpcError> {
+    let zi_hour_rule = match str_param(params, "zi_rule")? {
+        None => ZiHourRule::default(),
+        Some(name) => ZiHourRule::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = ZiHourRule::ALL.map(ZiHourRule::as_str).into();
+            RpcError::invalid_params(format!(
+                "invalid zi_rule '{name}': use one of {}",
+                names.join(", ")
+            ))
+        })?,
+    };
+    let solar_time = match params.get("longitude") {
+        None | Some(Value::Null) => None,
+        Some(value) => Some(
+            value
+                .as_f64()
+                .and_then(SolarTimeCorrection::new)
+                .ok_or_else(|| {
+                    RpcError::invalid_params("`longitude` must be a number in -180..=180")
+                })?,
+        ),
+    };
+    Ok(BaziOptions {
+        zi_hour_rule,
+        solar_time,
+    })
+}
+
+/// A pillar with its stem and branch split out.
+fn pillar_json(pillar: GanZhi) -> Value {
+    let (stem, branch) = (pillar.stem(), pillar.branch());
+    json!({
+        "ganzhi": pillar.to_string(),
+        "cycle_index": pillar.cycle_index(),
+        "stem": {
+            "name": stem.as_str(),
+            "index": stem.index(),
+            "element": stem.element().as_str(),
+            "yin_yang": stem.yin_yang().as_str(),
+        },
+        "branch": {
+            "name": branch.as_str(),
+            "index": branch.index(),
+            "element": branch.element().as_str(),
+            "yin_yang": branch.yin_yang().as_str(),
+            "zodiac": branch.zodiac(),
+        },
+    })
+}
+
+/// The four pillars, each as `{ganzhi, cycle_index, stem{..}, branch{..}}`.
+///
+/// Shared by [`rpc_call`] and `query-lunisolar --format json`, like the
+/// other `*_json` builders here.
+pub fn pillars_json(pillars: &BaziPillars) -> Value {
+    json!({
+        "year": pillar_json(pillars.year),
+        "month": pillar_json(pillars.month),
+        "day": pillar_json(pillars.day),
+        "hour": pillar_json(pillars.hour),
+    })
+}
+
+/// A table row with RFC 3339 (UTC+8) term times.
+pub fn year_data_json(row: &BaziYearData) -> Value {
+    json!({
+        "year": row.year,
+        "lunar_new_year": row.lunar_new_year,
+        "lichun_time": table_time_str(row.lichun_time),
+        "leap_month": (row.leap_month > 0).then_some(row.leap_month),
+        "new_moons": row.new_moons,
+        "jieqi": row
+            .jieqi
+            .iter()
+            .map(|term| json!({ "name": term.name, "time": table_time_str(term.time) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Previous and next term with RFC 3339 (UTC+8) times and distances in seconds.
+pub fn jieqi_context_json(ctx: &JieQiContext) -> Value {
+    json!({
+        "prev": {
+            "name": ctx.prev_name,
+            "time": table_rfc3339(ctx.prev_time),
+            "elapsed_seconds": ctx.diff_prev_seconds,
+        },
+        "next": {
+            "name": ctx.next_name,
+            "time": table_rfc3339(ctx.next_time),
+            "remaining_seconds": ctx.diff_next_seconds,
+        },
+    })
+}
+
+/// `{year, month, day, is_leap, text}`.
+pub fn lunar_date_json(lunar: &LunarDate) -> Value {
+    json!({
+        "year": lunar.year,
+        "month": lunar.month,
+        "day": lunar.day,
+        "is_leap": lunar.is_leap,
+        "text": lunar.to_string(),
+    })
+}
+
+fn nayin_json(pillar: GanZhi) -> Value {
+    json!({
+        "ganzhi": pillar.to_string(),
+        "cycle_index": pillar.cycle_index(),
+        "nayin": pillar.nayin(),
+    })
+}
+
+/// Runs one method with named `params`.
+///
+/// - `pillars` `{datetime, zi_rule?, longitude?}`: the four pillars.
+/// - `jieqi_context` `{datetime}`: previous and next solar term.
+/// - `year_data` `{year}`: the table row.
+/// - `lunar_date` `{date}`: Gregorian date to lunar date.
+/// - `nayin` `{year}` (sexagenary year, not split at LiChun) or `{ganzhi}`.
+///
+/// `datetime` is `YYYY-MM-DD[ HH:MM[:SS]]` in UTC+8 or RFC 3339 with an offset.
+pub fn rpc_call(method: &str, params: &Value) -> Result<Value, RpcError> {
+    let empty = Map::new();
+    let params = match params {
+        Value::Object(map) => map,
+        Value::Null => &empty,
+        _ => return Err(RpcError::invalid_params("params must be an object")),
+    };
+    match method {
+        "pillars" => {
+            let dt = parse_datetime(required_str(params, "datetime")?)?;
+            let pillars = try_get_pillars_with(dt, &bazi_options(params)?)?;
+            Ok(pillars_json(&pillars))
+        }
+        "jieqi_context" => {
+            let dt = parse_datetime(required_str(params, "datetime")?)?;
+            Ok(jieqi_context_json(&try_get_jieqi_context_tz(dt)?))
+        }
+        "year_data" => {
+            let year =
+                year_param(params)?.ok_or_else(|| RpcError::invalid_params("missing `year`"))?;
+            Ok(year_data_json(try_get_year_data(year)?))
+        }
+        "lunar_date" => {
+            let value = required_str(params, "date")?;
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+                RpcError::invalid_params(format!("invalid date '{value}': use YYYY-MM-DD"))
+            })?;
+            Ok(lunar_date_json(&try_to_lunar_date(date)?))
+        }
+        "nayin" => match (year_param(params)?, str_param(params, "ganzhi")?) {
+            (Some(year), None) => Ok(nayin_json(GanZhi::from_index(get_cycle_index(year)))),
+            (None, Some(name)) => name
+                .parse()
+                .map(nayin_json)
+                .map_err(|e| RpcError::invalid_params(format!("{e}"))),
+            _ => Err(RpcError::invalid_params(
+                "give exactly one of `year` or `ganzhi`",
+            )),
+        },
+        _ => Err(RpcError::new(
+            RpcError::METHOD_NOT_FOUND,
+            format!(
+                "unknown method '{method}'; methods: {}",
+                RPC_METHODS.join(", ")
+            ),
+        )),
+    }
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => json!({ "jsonrpc": "2.0", "id": id, "error": e.to_json() }),
+    }
+}
+
+/// Answers one request object; `None` for a notification (no `id`).
+fn handle_request(
+    request: &Value,
+    call: &impl Fn(&str, &Value) -> Result<Value, RpcError>,
+) -> Option<Value> {
+    let id = request.get("id").cloned();
+    let method = request.get("method").and_then(Value::as_str);
+    let result = match (request.get("jsonrpc").and_then(Value::as_str), method) {
+        (Some("2.0"), Some(method)) => call(method, request.get("params").unwrap_or(&Value::Null)),
+        _ => Err(RpcError::new(
+            RpcError::INVALID_REQUEST,
+            "expected {\"jsonrpc\": \"2.0\", \"method\": ..., \"params\": {...}, \"id\": ...}",
+        )),
+    };
+    match id {
+        Some(id) => Some(response(id, result)),
+        // Malformed requests are answered even without an id.
+        None if method.is_none() => Some(response(Value::Null, result)),
+        None => None,
+    }
+}
+
+/// Answers one line of line-delimited JSON-RPC 2.0: a request object or a
+/// batch array. `None` when nothing is to be written back (notifications,
+/// blank lines).
+pub fn handle_rpc_line(line: &str) -> Option<String> {
+    handle_rpc_line_with(line, rpc_call)
+}
+
+/// [`handle_rpc_line`] with `call` answering each method in place of
+/// [`rpc_call`], for servers that speak their own methods over this framing.
+pub fn handle_rpc_line_with(
+    line: &str,
+    call: impl Fn(&str, &Value) -> Result<Value, RpcError>,
+) -> Option<String> {
+    if line.trim().is_empty() {
+        return None;
+    }
+    let reply = match serde_json::from_str::<Value>(line) {
+        Err(e) => Some(response(
+            Value::Null,
+            Err(RpcError::new(
+                RpcError::PARSE_ERROR,
+                format!("parse error: {e}"),
+            )),
+        )),
+        Ok(Value::Array(requests)) if !requests.is_empty() => {
+            let replies: Vec<Value> = requests
+                .iter()
+                .filter_map(|request| handle_request(request, &call))
+                .collect();
+            (!replies.is_empty()).then_some(Value::Array(replies))
+        }
+        Ok(request) => handle_request(&request, &call),
+    };
+    reply.map(|value| value.to_string())
+}
+
+/// Serves line-delimited JSON-RPC until `input` ends, flushing each reply.
+///
+/// The year table is parsed before the first request, so every call is
+/// answered from memory. A line that is not UTF-8 gets a parse error reply.
+pub fn serve(input: impl BufRead, output: impl Write) -> io::Result<()> {
+    let warm_up = table_zone().with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    let _ = try_get_jieqi_context_tz(warm_up);
+    let _ = try_to_lunar_date(warm_up.date_naive());
+    serve_with(input, output, rpc_call)
+}
+
+/// [`serve`] with `call` answering each method in place of [`rpc_call`].
+pub fn serve_with(
+    mut input: impl BufRead,
+    mut output: impl Write,
+    call: impl Fn(&str, &Value) -> Result<Value, RpcError>,
+) -> io::Result<()> {
+    let mut bytes = Vec::new();
+    loop {
+        bytes.clear();
+        if input.read_until(b'\n', &mut bytes)? == 0 {
+            return Ok(());
+        }
+        let reply = match std::str::from_utf8(&bytes) {
+            Ok(line) => handle_rpc_line_with(line, &call),
+            Err(e) => Some(
+                response(
+                    Value::Null,
+                    Err(RpcError::new(
+                        RpcError::PARSE_ERROR,
+                        format!("parse error: line is not valid UTF-8 ({e})"),
+                    )),
+                )
+                .to_string(),
+            ),
+        };
+        if let Some(reply) = reply {
+            writeln!(output, "{reply}")?;
+            output.flush()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(line: &str) -> Value {
+        serde_json::from_str(&handle_rpc_line(line).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn methods_answer_from_the_table() {
+        let pillars = rpc_call("pillars", &json!({ "datetime": "1985-04-21 08:00" })).unwrap();
+        let names: Vec<&str> = ["year", "month", "day", "hour"]
+            .iter()
+            .map(|p| pillars[p]["ganzhi"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["乙丑", "庚辰", "庚寅", "庚辰"]);
+        assert_eq!(pillars["day"]["stem"]["name"], "庚");
+        assert_eq!(pillars["day"]["branch"]["zodiac"], "虎");
+
+        // Same instant given in New York time.
+        let shifted = rpc_call(
+            "pillars",
+            &json!({ "datetime": "1985-04-20T19:00:00-05:00" }),
+        )
+        .unwrap();
+        assert_eq!(shifted["year"], pillars["year"]);
+        assert_eq!(shifted["month"], pillars["month"]);
+
+        let ctx = rpc_call("jieqi_context", &json!({ "datetime": "2026-02-04" })).unwrap();
+        assert_eq!(ctx["next"]["name"], "立春");
+        assert_eq!(ctx["next"]["time"], "2026-02-04T04:02:08+08:00");
+        assert_eq!(ctx["next"]["remaining_seconds"], 14528);
+
+        let row = rpc_call("year_data", &json!({ "year": 2023 })).unwrap();
+        assert_eq!(row["leap_month"], 2);
+        assert_eq!(row["lichun_time"], "2023-02-04T10:42:33+08:00");
+
+        let lunar = rpc_call("lunar_date", &json!({ "date": "2023-03-22" })).unwrap();
+        assert_eq!(lunar["text"], "2023年闰二月初一");
+
+        let by_year = rpc_call("nayin", &json!({ "year": 1984 })).unwrap();
+        assert_eq!(
+            by_year,
+            json!({ "ganzhi": "甲子", "cycle_index": 0, "nayin": "海中金" })
+        );
+        let by_pillar = rpc_call("nayin", &json!({ "ganzhi": "甲子" })).unwrap();
+        assert_eq!(by_pillar, by_year);
+    }
+
+    #[test]
+    fn errors_use_json_rpc_codes() {
+        let unknown = rpc_call("moon_phase", &json!({})).unwrap_err();
+        assert_eq!(unknown.code, RpcError::METHOD_NOT_FOUND);
+        let missing = rpc_call("pillars", &json!({})).unwrap_err();
+        assert_eq!(missing.code, RpcError::INVALID_PARAMS);
+        let bad_rule = rpc_call(
+            "pillars",
+            &json!({ "datetime": "2024-01-01", "zi_rule": "noon" }),
+        )
+        .unwrap_err();
+        assert_eq!(bad_rule.code, RpcError::INVALID_PARAMS);
+        let outside = rpc_call("year_data", &json!({ "year": 2100 })).unwrap_err();
+        assert_eq!(outside.code, RpcError::UNAVAILABLE);
+        assert!(outside.message.contains("2100"));
+        let both = rpc_call("nayin", &json!({ "year": 1984, "ganzhi": "甲子" })).unwrap_err();
+        assert_eq!(both.code, RpcError::INVALID_PARAMS);
+        // `nayin` has no table bound; extreme years still map into the cycle.
+        let lowest = rpc_call("nayin", &json!({ "year": i32::MIN })).unwrap();
+        assert_eq!(lowest["cycle_index"], 48);
+        let too_big = rpc_call("nayin", &json!({ "year": i64::from(i32::MAX) + 1 })).unwrap_err();
+        assert_eq!(too_big.code, RpcError::INVALID_PARAMS);
+    }
+
+    #[test]
+    fn line_protocol() {
+        let reply = call(r#"{"jsonrpc":"2.0","id":7,"method":"nayin","params":{"year":2024}}"#);
+        assert_eq!(reply["id"], 7);
+        assert_eq!(reply["result"]["nayin"], "覆灯火");
+
+        let parse = call("{not json");
+        assert_eq!(parse["error"]["code"], RpcError::PARSE_ERROR);
+        assert!(parse["id"].is_null());
+        let invalid = call(r#"{"id":1,"method":"nayin"}"#);
+        assert_eq!(invalid["error"]["code"], RpcError::INVALID_REQUEST);
+
+        // Notifications get no reply, also inside a batch.
+        assert!(
+            handle_rpc_line(r#"{"jsonrpc":"2.0","method":"nayin","params":{"year":1}}"#).is_none()
+        );
+        assert!(handle_rpc_line("  ").is_none());
+        let batch = call(
+            r#"[{"jsonrpc":"2.0","id":"a","method":"year_data","params":{"year":1900}},
+                {"jsonrpc":"2.0","method":"nayin","params":{"year":1}},
+                {"jsonrpc":"2.0","id":"b","method":"nope"}]"#
+                .replace('\n', " ")
+                .as_str(),
+        );
+        let replies = batch.as_array().unwrap();
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[0]["result"]["year"], 1900);
+        assert_eq!(replies[1]["error"]["code"], RpcError::METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn serve_writes_one_line_per_reply() {
+        let input = "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"lunar_date\",\"params\":{\"date\":\"2024-02-10\"}}\n\n{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"year_data\",\"params\":{\"year\":\"x\"}}\n";
+        let mut output = Vec::new();
+        serve(input.as_bytes(), &mut output).unwrap();
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["result"]["text"], "2024年正月初一");
+        assert_eq!(lines[1]["error"]["code"], RpcError::INVALID_PARAMS);
+    }
+
+    #[test]
+    fn serve_survives_invalid_utf8() {
+        let input = b"\xff\xfe{}\n{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"nayin\",\"params\":{\"year\":2024}}\n";
+        let mut output = Vec::new();
+        serve(&input[..], &mut output).unwrap();
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["error"]["code"], RpcError::PARSE_ERROR);
+        assert!(lines[0]["id"].is_null());
+        assert_eq!(lines[1]["result"]["nayin"], "覆灯火");
+    }
+}
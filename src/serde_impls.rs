@@ -0,0 +1,434 @@
+//! Hand-written serde impls for the `serde` feature.
+//!
+//! Stems, branches, 干支, elements and polarities serialize as their
+//! characters (`"甲"`, `"子"`, `"甲子"`, `"木"`, `"阳"`). Datetimes are ISO 8601
+//! without an offset, in the UTC+8 table frame. The static rule tables only
+//! serialize, since they borrow `'static` data.
+//!
+//! Types carrying `&'static str` table data deserialize through an owned
+//! mirror here: derived impls would only accept `'static` input. Table rows
+//! (`BaziYearData`, `JieQiData`) resolve to the matching `BAZI_YEAR_DATA`
+//! entry and are rejected when they differ from it.
+
+use crate::almanac::{Activity, AlmanacDay, DayClash, DayOfficer, Mansion};
+use crate::bazi_calc::JieQiContext;
+use crate::data::{BAZI_YEAR_DATA, BaziYearData, JieQiData};
+use crate::festivals::NineOrdinal;
+use crate::flow::FlowMonth;
+use crate::ganzhi::{EarthlyBranch, Element, GanZhi, HeavenlyStem, YinYang};
+use crate::lunar_date::LunarDate;
+use crate::solar_time::SolarTimeCorrection;
+use crate::table_lookup::table_index;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::de::Error as _;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes as `as_str()`, deserializes by matching it against `$variants`.
+macro_rules! serde_as_str {
+    ($ty:ident, $what:literal, $variants:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = String::deserialize(deserializer)?;
+                $variants
+                    .into_iter()
+                    .find(|v| v.as_str() == name)
+                    .ok_or_else(|| D::Error::custom(format!("unknown {}: '{}'", $what, name)))
+            }
+        }
+    };
+}
+
+serde_as_str!(Element, "element", Element::ALL);
+serde_as_str!(YinYang, "yin/yang", [YinYang::Yang, YinYang::Yin]);
+serde_as_str!(
+    HeavenlyStem,
+    "heavenly stem",
+    (0..10).map(HeavenlyStem::from_index)
+);
+serde_as_str!(
+    EarthlyBranch,
+    "earthly branch",
+    (0..12).map(EarthlyBranch::from_index)
+);
+
+impl Serialize for GanZhi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GanZhi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Table `YYYY-MM-DD HH:MM:SS` as ISO 8601 (`YYYY-MM-DDTHH:MM:SS`).
+fn iso_table_time(time: &str) -> String {
+    time.replacen(' ', "T", 1)
+}
+
+impl Serialize for JieQiData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("JieQiData", 2)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("time", &iso_table_time(self.time))?;
+        state.end()
+    }
+}
+
+impl Serialize for BaziYearData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("BaziYearData", 6)?;
+        state.serialize_field("year", &self.year)?;
+        state.serialize_field("lunar_new_year", self.lunar_new_year)?;
+        state.serialize_field("lichun_time", &iso_table_time(self.lichun_time))?;
+        state.serialize_field("leap_month", &self.leap_month)?;
+        state.serialize_field("new_moons", self.new_moons)?;
+        state.serialize_field("jieqi", &self.jieqi)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct OwnedJieQiData {
+    name: String,
+    time: String,
+}
+
+impl OwnedJieQiData {
+    fn matches(&self, term: &JieQiData) -> bool {
+        self.name == term.name && self.time == iso_table_time(term.time)
+    }
+}
+
+impl<'de> Deserialize<'de> for JieQiData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = OwnedJieQiData::deserialize(deserializer)?;
+        BAZI_YEAR_DATA
+            .iter()
+            .flat_map(|row| &row.jieqi)
+            .find(|term| owned.matches(term))
+            .map(|term| JieQiData {
+                name: term.name,
+                time: term.time,
+            })
+            .ok_or_else(|| {
+                D::Error::custom(format!(
+                    "solar term {} at {} is not in the table",
+                    owned.name, owned.time
+                ))
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct OwnedBaziYearData {
+    year: i32,
+    lunar_new_year: String,
+    lichun_time: String,
+    leap_month: u8,
+    new_moons: Vec<String>,
+    jieqi: Vec<OwnedJieQiData>,
+}
+
+impl<'de> Deserialize<'de> for BaziYearData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = OwnedBaziYearData::deserialize(deserializer)?;
+        let row = &BAZI_YEAR_DATA[table_index(owned.year).map_err(D::Error::custom)?];
+        let same = owned.lunar_new_year == row.lunar_new_year
+            && owned.lichun_time == iso_table_time(row.lichun_time)
+            && owned.leap_month == row.leap_month
+            && owned.new_moons.iter().eq(row.new_moons)
+            && owned.jieqi.len() == row.jieqi.len()
+            && owned
+                .jieqi
+                .iter()
+                .zip(&row.jieqi)
+                .all(|(o, t)| o.matches(t));
+        if !same {
+            return Err(D::Error::custom(format!(
+                "year {} does not match the table row",
+                owned.year
+            )));
+        }
+        Ok(BaziYearData {
+            year: row.year,
+            lunar_new_year: row.lunar_new_year,
+            lichun_time: row.lichun_time,
+            leap_month: row.leap_month,
+            new_moons: row.new_moons,
+            jieqi: std::array::from_fn(|i| JieQiData {
+                name: row.jieqi[i].name,
+                time: row.jieqi[i].time,
+            }),
+        })
+    }
+}
+
+/// The table's `'static` name of a solar term.
+fn term_name<E: serde::de::Error>(name: &str) -> Result<&'static str, E> {
+    BAZI_YEAR_DATA[0]
+        .jieqi
+        .iter()
+        .map(|term| term.name)
+        .find(|&term| term == name)
+        .ok_or_else(|| E::custom(format!("unknown solar term: '{}'", name)))
+}
+
+#[derive(Deserialize)]
+struct OwnedJieQiContext {
+    prev_name: String,
+    prev_time: NaiveDateTime,
+    next_name: String,
+    next_time: NaiveDateTime,
+    diff_prev_seconds: i64,
+    diff_next_seconds: i64,
+}
+
+impl<'de> Deserialize<'de> for JieQiContext {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = OwnedJieQiContext::deserialize(deserializer)?;
+        Ok(JieQiContext {
+            prev_name: term_name(&owned.prev_name)?,
+            prev_time: owned.prev_time,
+            next_name: term_name(&owned.next_name)?,
+            next_time: owned.next_time,
+            diff_prev_seconds: owned.diff_prev_seconds,
+            diff_next_seconds: owned.diff_next_seconds,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct OwnedFlowMonth {
+    pillar: GanZhi,
+    jie: String,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+}
+
+impl<'de> Deserialize<'de> for FlowMonth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = OwnedFlowMonth::deserialize(deserializer)?;
+        Ok(FlowMonth {
+            pillar: owned.pillar,
+            jie: term_name(&owned.jie)?,
+            start: owned.start,
+            end: owned.end,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct OwnedAlmanacDay {
+    date: NaiveDate,
+    lunar: Option<LunarDate>,
+    year: GanZhi,
+    month: GanZhi,
+    day: GanZhi,
+    officer: DayOfficer,
+    mansion: Mansion,
+    clash: DayClash,
+    term: Option<String>,
+    si_jue: bool,
+    si_li: bool,
+    good: Vec<Activity>,
+    bad: Vec<Activity>,
+}
+
+impl<'de> Deserialize<'de> for AlmanacDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = OwnedAlmanacDay::deserialize(deserializer)?;
+        Ok(AlmanacDay {
+            date: owned.date,
+            lunar: owned.lunar,
+            year: owned.year,
+            month: owned.month,
+            day: owned.day,
+            officer: owned.officer,
+            mansion: owned.mansion,
+            clash: owned.clash,
+            term: owned.term.as_deref().map(term_name).transpose()?,
+            si_jue: owned.si_jue,
+            si_li: owned.si_li,
+            good: owned.good,
+            bad: owned.bad,
+        })
+    }
+}
+
+/// Goes through [`NineOrdinal::new`], so `{"Nine": 0}` is rejected.
+impl<'de> Deserialize<'de> for NineOrdinal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let n = u8::deserialize(deserializer)?;
+        NineOrdinal::new(n)
+            .ok_or_else(|| D::Error::custom(format!("数九 period {} is outside 1..=9", n)))
+    }
+}
+
+#[derive(Deserialize)]
+struct OwnedSolarTimeCorrection {
+    longitude: f64,
+}
+
+/// Goes through [`SolarTimeCorrection::new`], so the longitude stays in range.
+impl<'de> Deserialize<'de> for SolarTimeCorrection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let longitude = OwnedSolarTimeCorrection::deserialize(deserializer)?.longitude;
+        SolarTimeCorrection::new(longitude).ok_or_else(|| {
+            D::Error::custom(format!("longitude {} is outside -180.0..=180.0", longitude))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::almanac::{AlmanacDay, try_almanac};
+    use crate::bazi_calc::{BaziPillars, JieQiContext, try_get_jieqi_context};
+    use crate::data::{BaziYearData, JieQiData};
+    use crate::festivals::{Festival, FestivalDay};
+    use crate::flow::{FlowMonth, try_flow_months};
+    use crate::ganzhi::{EarthlyBranch, GanZhi, HeavenlyStem};
+    use crate::lunar_date::{LunarDate, try_to_lunar_date};
+    use crate::solar_time::SolarTimeCorrection;
+    use crate::table_lookup::try_get_year_data;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use serde_json::json;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn datetime(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn ganzhi_family_uses_characters() {
+        let pillars = BaziPillars {
+            year: "乙巳".parse().unwrap(),
+            month: "己丑".parse().unwrap(),
+            day: "己酉".parse().unwrap(),
+            hour: "甲子".parse().unwrap(),
+        };
+        let value = serde_json::to_value(pillars).unwrap();
+        assert_eq!(
+            value,
+            json!({ "year": "乙巳", "month": "己丑", "day": "己酉", "hour": "甲子" })
+        );
+        assert_eq!(
+            serde_json::from_value::<BaziPillars>(value).unwrap(),
+            pillars
+        );
+        assert_eq!(
+            serde_json::to_value(HeavenlyStem::Geng).unwrap(),
+            json!("庚")
+        );
+        assert_eq!(
+            serde_json::from_value::<EarthlyBranch>(json!("亥")).unwrap(),
+            EarthlyBranch::Hai
+        );
+        assert!(serde_json::from_value::<GanZhi>(json!("甲丑")).is_err());
+        assert!(serde_json::from_value::<HeavenlyStem>(json!("子")).is_err());
+    }
+
+    #[test]
+    fn jieqi_context_round_trips_with_iso_times() {
+        let ctx = try_get_jieqi_context(datetime("2026-02-04 00:00:00")).unwrap();
+        let value = serde_json::to_value(&ctx).unwrap();
+        assert_eq!(value["prev_name"], "大寒");
+        assert_eq!(value["next_time"], "2026-02-04T04:02:08");
+        let back: JieQiContext = serde_json::from_value(value).unwrap();
+        assert_eq!(back.next_name, ctx.next_name);
+        assert_eq!(back.prev_time, ctx.prev_time);
+        assert_eq!(back.diff_next_seconds, ctx.diff_next_seconds);
+
+        let mut unknown = serde_json::to_value(&ctx).unwrap();
+        unknown["prev_name"] = json!("大暑假");
+        assert!(serde_json::from_value::<JieQiContext>(unknown).is_err());
+    }
+
+    #[test]
+    fn table_row_serializes_iso_times() {
+        let value = serde_json::to_value(try_get_year_data(2026).unwrap()).unwrap();
+        assert_eq!(value["lunar_new_year"], "2026-02-17");
+        assert_eq!(value["lichun_time"], "2026-02-04T04:02:08");
+        assert_eq!(value["jieqi"].as_array().unwrap().len(), 24);
+        assert_eq!(value["jieqi"][0]["name"], "大雪");
+        assert!(value["jieqi"][0]["time"].as_str().unwrap().contains('T'));
+
+        let row: BaziYearData = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(row.year, 2026);
+        assert_eq!(row.lichun_time, "2026-02-04 04:02:08");
+        assert_eq!(serde_json::to_value(&row).unwrap(), value);
+        let term: JieQiData = serde_json::from_value(value["jieqi"][4].clone()).unwrap();
+        assert_eq!((term.name, term.time), ("立春", "2026-02-04 04:02:08"));
+
+        let mut edited = value.clone();
+        edited["lichun_time"] = json!("2026-02-04T04:02:09");
+        assert!(serde_json::from_value::<BaziYearData>(edited).is_err());
+        let mut outside = value.clone();
+        outside["year"] = json!(1899);
+        assert!(serde_json::from_value::<BaziYearData>(outside).is_err());
+        let moved = json!({ "name": "立春", "time": "2026-02-05T04:02:08" });
+        assert!(serde_json::from_value::<JieQiData>(moved).is_err());
+    }
+
+    #[test]
+    fn result_types_round_trip() {
+        let lunar = try_to_lunar_date(date(2023, 3, 22)).unwrap();
+        let back: LunarDate = serde_json::from_value(serde_json::to_value(lunar).unwrap()).unwrap();
+        assert_eq!(back, lunar);
+
+        let day = try_almanac(date(2024, 2, 4)).unwrap();
+        let value = serde_json::to_value(&day).unwrap();
+        assert_eq!(value["term"], "立春");
+        assert_eq!(value["date"], "2024-02-04");
+        assert_eq!(serde_json::from_value::<AlmanacDay>(value).unwrap(), day);
+        let plain = try_almanac(date(2024, 2, 10)).unwrap();
+        let value = serde_json::to_value(&plain).unwrap();
+        assert!(value["term"].is_null());
+        assert_eq!(serde_json::from_value::<AlmanacDay>(value).unwrap(), plain);
+
+        let correction = SolarTimeCorrection::new(116.4).unwrap();
+        let value = serde_json::to_value(correction).unwrap();
+        assert_eq!(value, json!({ "longitude": 116.4 }));
+        assert_eq!(
+            serde_json::from_value::<SolarTimeCorrection>(value).unwrap(),
+            correction
+        );
+        assert!(
+            serde_json::from_value::<SolarTimeCorrection>(json!({ "longitude": 500.0 })).is_err()
+        );
+
+        let months = try_flow_months(2027).unwrap();
+        let back: FlowMonth =
+            serde_json::from_value(serde_json::to_value(months[0]).unwrap()).unwrap();
+        assert_eq!(back, months[0]);
+
+        let ninth = FestivalDay {
+            date: date(2025, 3, 1),
+            festival: Festival::nine(8).unwrap(),
+        };
+        let back: FestivalDay =
+            serde_json::from_value(serde_json::to_value(ninth).unwrap()).unwrap();
+        assert_eq!(back, ninth);
+        assert_eq!(
+            serde_json::to_value(ninth.festival).unwrap(),
+            json!({ "Nine": 8 })
+        );
+        for n in [0, 10, 200] {
+            assert!(serde_json::from_value::<Festival>(json!({ "Nine": n })).is_err());
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{EarthlyBranch, GanZhi};
+use crate::interactions::PillarPosition;
+use std::fmt;
+
+use EarthlyBranch::*;
+
+/// Symbolic stars (神煞).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Star {
+    /// 天乙贵人
+    TianYiNoble,
+    /// 文昌
+    WenChang,
+    /// 禄神
+    Prosperity,
+    /// 羊刃
+    YangBlade,
+    /// 桃花 (咸池)
+    PeachBlossom,
+    /// 驿马
+    TravelHorse,
+    /// 华盖
+    FlowerCanopy,
+    /// 将星
+    GeneralStar,
+    /// 红鸾
+    HongLuan,
+    /// 天喜
+    TianXi,
+    /// 空亡 (旬空)
+    Void,
+}
+
+impl Star {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Star::TianYiNoble => "天乙贵人",
+            Star::WenChang => "文昌",
+            Star::Prosperity => "禄神",
+            Star::YangBlade => "羊刃",
+            Star::PeachBlossom => "桃花",
+            Star::TravelHorse => "驿马",
+            Star::FlowerCanopy => "华盖",
+            Star::GeneralStar => "将星",
+            Star::HongLuan => "红鸾",
+            Star::TianXi => "天喜",
+            Star::Void => "空亡",
+        }
+    }
+}
+
+impl fmt::Display for Star {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The chart element a rule is looked up from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StarKey {
+    DayStem,
+    YearStem,
+    YearBranch,
+    DayBranch,
+    /// The day pillar's 旬 (used by 空亡).
+    DayPillar,
+}
+
+impl StarKey {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StarKey::DayStem => "日干",
+            StarKey::YearStem => "年干",
+            StarKey::YearBranch => "年支",
+            StarKey::DayBranch => "日支",
+            StarKey::DayPillar => "日柱",
+        }
+    }
+
+    fn position(self) -> PillarPosition {
+        match self {
+            StarKey::DayStem | StarKey::DayBranch | StarKey::DayPillar => PillarPosition::Day,
+            StarKey::YearStem | StarKey::YearBranch => PillarPosition::Year,
+        }
+    }
+}
+
+impl fmt::Display for StarKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Branches a star falls on, given the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StarTargets {
+    /// Indexed by the key stem (甲..癸).
+    ByStem([&'static [EarthlyBranch]; 10]),
+    /// Indexed by the key branch (子..亥).
+    ByBranch([EarthlyBranch; 12]),
+    /// The two void branches of the key pillar's 旬.
+    XunVoid,
+}
+
+/// One row of the 神煞 rule table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StarRule {
+    pub star: Star,
+    /// Every key the star is looked up from; each produces its own hits.
+    pub keys: &'static [StarKey],
+    pub targets: StarTargets,
+}
+
+/// Rule table used by [`shen_sha`].
+///
+/// Stem-keyed stars are checked on all branches, including the key pillar's own.
+/// Branch- and pillar-keyed stars are checked on the other pillars only.
+pub const STAR_RULES: &[StarRule] = &[
+    StarRule {
+        star: Star::TianYiNoble,
+        keys: &[StarKey::DayStem, StarKey::YearStem],
+        // 甲戊庚牛羊, 乙己鼠猴乡, 丙丁猪鸡位, 壬癸兔蛇藏, 六辛逢马虎.
+        targets: StarTargets::ByStem([
+            &[Chou, Wei],
+            &[Zi, Shen],
+            &[Hai, You],
+            &[Hai, You],
+            &[Chou, Wei],
+            &[Zi, Shen],
+            &[Chou, Wei],
+            &[Wu, Yin],
+            &[Mao, Si],
+            &[Mao, Si],
+        ]),
+    },
+    StarRule {
+        star: Star::WenChang,
+        keys: &[StarKey::DayStem],
+        targets: StarTargets::ByStem([
+            &[Si],
+            &[Wu],
+            &[Shen],
+            &[You],
+            &[Shen],
+            &[You],
+            &[Hai],
+            &[Zi],
+            &[Yin],
+            &[Mao],
+        ]),
+    },
+    StarRule {
+        star: Star::Prosperity,
+        keys: &[StarKey::DayStem],
+        targets: StarTargets::ByStem([
+            &[Yin],
+            &[Mao],
+            &[Si],
+            &[Wu],
+            &[Si],
+            &[Wu],
+            &[Shen],
+            &[You],
+            &[Hai],
+            &[Zi],
+        ]),
+    },
+    StarRule {
+        star: Star::YangBlade,
+        keys: &[StarKey::DayStem],
+        // The branch after 禄; yin stems follow the same forward step.
+        targets: StarTargets::ByStem([
+            &[Mao],
+            &[Chen],
+            &[Wu],
+            &[Wei],
+            &[Wu],
+            &[Wei],
+            &[You],
+            &[Xu],
+            &[Zi],
+            &[Chou],
+        ]),
+    },
+    StarRule {
+        star: Star::PeachBlossom,
+        keys: &[StarKey::YearBranch, StarKey::DayBranch],
+        // 申子辰 酉, 寅午戌 卯, 巳酉丑 午, 亥卯未 子.
+        targets: StarTargets::ByBranch([You, Wu, Mao, Zi, You, Wu, Mao, Zi, You, Wu, Mao, Zi]),
+    },
+    StarRule {
+        star: Star::TravelHorse,
+        keys: &[StarKey::YearBranch, StarKey::DayBranch],
+        // 申子辰 寅, 寅午戌 申, 巳酉丑 亥, 亥卯未 巳.
+        targets: StarTargets::ByBranch([
+            Yin, Hai, Shen, Si, Yin, Hai, Shen, Si, Yin, Hai, Shen, Si,
+        ]),
+    },
+    StarRule {
+        star: Star::FlowerCanopy,
+        keys: &[StarKey::YearBranch, StarKey::DayBranch],
+        // 申子辰 辰, 寅午戌 戌, 巳酉丑 丑, 亥卯未 未.
+        targets: StarTargets::ByBranch([
+            Chen, Chou, Xu, Wei, Chen, Chou, Xu, Wei, Chen, Chou, Xu, Wei,
+        ]),
+    },
+    StarRule {
+        star: Star::GeneralStar,
+        keys: &[StarKey::YearBranch, StarKey::DayBranch],
+        // The center of the branch's 三合 frame.
+        targets: StarTargets::ByBranch([Zi, You, Wu, Mao, Zi, You, Wu, Mao, Zi, You, Wu, Mao]),
+    },
+    StarRule {
+        star: Star::HongLuan,
+        keys: &[StarKey::YearBranch],
+        targets: StarTargets::ByBranch([Mao, Yin, Chou, Zi, Hai, Xu, You, Shen, Wei, Wu, Si, Chen]),
+    },
+    StarRule {
+        star: Star::TianXi,
+        keys: &[StarKey::YearBranch],
+        targets: StarTargets::ByBranch([You, Shen, Wei, Wu, Si, Chen, Mao, Yin, Chou, Zi, Hai, Xu]),
+    },
+    StarRule {
+        star: Star::Void,
+        keys: &[StarKey::DayPillar],
+        targets: StarTargets::XunVoid,
+    },
+];
+
+/// A star landing on one pillar's branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StarHit {
+    pub star: Star,
+    /// What the star was looked up from.
+    pub key: StarKey,
+    /// Pillar whose branch carries the star.
+    pub position: PillarPosition,
+    pub branch: EarthlyBranch,
+}
+
+impl fmt::Display for StarHit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}支{} (by {})",
+            self.star, self.position, self.branch, self.key
+        )
+    }
+}
+
+/// Stars of the four pillars, looked up with [`STAR_RULES`].
+pub fn shen_sha(pillars: &BaziPillars) -> Vec<StarHit> {
+    shen_sha_with(pillars, &[])
+}
+
+/// Like [`shen_sha`], also checking `extra` pillars such as the luck or annual pillar.
+///
+/// Keys are always taken from the natal year and day pillars.
+pub fn shen_sha_with(pillars: &BaziPillars, extra: &[(PillarPosition, GanZhi)]) -> Vec<StarHit> {
+    let mut chart = vec![
+        (PillarPosition::Year, pillars.year),
+        (PillarPosition::Month, pillars.month),
+        (PillarPosition::Day, pillars.day),
+        (PillarPosition::Hour, pillars.hour),
+    ];
+    chart.extend_from_slice(extra);
+
+    let mut hits = Vec::new();
+    for rule in STAR_RULES {
+        for &key in rule.keys {
+            let key_pillar = match key.position() {
+                PillarPosition::Year => pillars.year,
+                _ => pillars.day,
+            };
+            let void = key_pillar.void_branches();
+            let (targets, include_key_pillar): (&[EarthlyBranch], bool) = match (&rule.targets, key)
+            {
+                (StarTargets::ByStem(table), StarKey::DayStem | StarKey::YearStem) => {
+                    (table[key_pillar.stem().index()], true)
+                }
+                (StarTargets::ByBranch(table), _) => (
+                    std::slice::from_ref(&table[key_pillar.branch().index()]),
+                    false,
+                ),
+                (StarTargets::XunVoid, _) => (&void, false),
+                _ => continue,
+            };
+            for &(position, gz) in &chart {
+                if (include_key_pillar || position != key.position())
+                    && targets.contains(&gz.branch())
+                {
+                    hits.push(StarHit {
+                        star: rule.star,
+                        key,
+                        position,
+                        branch: gz.branch(),
+                    });
+                }
+            }
+        }
+    }
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(hits: &[StarHit]) -> Vec<String> {
+        hits.iter().map(|h| h.to_string()).collect()
+    }
+
+    #[test]
+    fn known_chart() {
+        // 乙丑 庚辰 庚寅 庚辰: 庚 noble on 丑未, 丑 year 红鸾 on 寅. The 丑 year's
+        // 华盖 is 丑 itself (skipped) and the 庚寅 day (甲申旬) voids 午未, absent here.
+        let hits = shen_sha(&"乙丑 庚辰 庚寅 庚辰".parse().unwrap());
+        assert_eq!(
+            rendered(&hits),
+            ["天乙贵人 年支丑 (by 日干)", "红鸾 日支寅 (by 年支)",]
+        );
+    }
+
+    #[test]
+    fn branch_keyed_stars() {
+        // 甲子 year: 桃花 酉, 驿马 寅, 华盖 辰, 将星 子.
+        let hits = shen_sha(&"甲子 丙寅 丁酉 甲辰".parse().unwrap());
+        let found: Vec<(Star, StarKey, PillarPosition)> =
+            hits.iter().map(|h| (h.star, h.key, h.position)).collect();
+        assert!(found.contains(&(Star::PeachBlossom, StarKey::YearBranch, PillarPosition::Day)));
+        assert!(found.contains(&(
+            Star::TravelHorse,
+            StarKey::YearBranch,
+            PillarPosition::Month
+        )));
+        assert!(found.contains(&(
+            Star::FlowerCanopy,
+            StarKey::YearBranch,
+            PillarPosition::Hour
+        )));
+        // 丁 day stem: noble 亥酉 includes its own 酉, 文昌 酉 as well.
+        assert!(found.contains(&(Star::TianYiNoble, StarKey::DayStem, PillarPosition::Day)));
+        assert!(found.contains(&(Star::WenChang, StarKey::DayStem, PillarPosition::Day)));
+        // Year stem 甲: noble 丑未, none present.
+        assert!(!found.iter().any(|(_, key, _)| *key == StarKey::YearStem));
+    }
+
+    #[test]
+    fn void_on_extra_pillars() {
+        // 丙寅 day (甲子旬) voids 戌亥; the 癸亥 annual pillar lands on 亥.
+        let pillars: BaziPillars = "庚午 戊子 丙寅 戊子".parse().unwrap();
+        let annual: GanZhi = "癸亥".parse().unwrap();
+        let hits = shen_sha_with(&pillars, &[(PillarPosition::Annual, annual)]);
+        assert!(hits.iter().any(|h| h.star == Star::Void
+            && h.position == PillarPosition::Annual
+            && h.branch == Hai));
+    }
+
+    #[test]
+    fn rule_table_covers_every_key() {
+        for rule in STAR_RULES {
+            assert!(!rule.keys.is_empty(), "{}", rule.star);
+            for key in rule.keys {
+                let stem_key = matches!(key, StarKey::DayStem | StarKey::YearStem);
+                assert_eq!(
+                    stem_key,
+                    matches!(rule.targets, StarTargets::ByStem(_)),
+                    "{} by {}",
+                    rule.star,
+                    key
+                );
+            }
+        }
+    }
+}
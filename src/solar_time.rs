@@ -0,0 +1,78 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, TimeZone, Timelike};
+use std::f64::consts::PI;
+
+/// True solar time (真太阳时) correction for a birth longitude.
+///
+/// Apparent solar time = UTC + longitude × 4 min/° + equation of time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolarTimeCorrection {
+    /// Birth longitude in degrees, east positive (-180.0..=180.0).
+    longitude: f64,
+}
+
+impl SolarTimeCorrection {
+    /// Returns `None` for longitudes outside -180.0..=180.0.
+    pub fn new(longitude: f64) -> Option<Self> {
+        (-180.0..=180.0)
+            .contains(&longitude)
+            .then_some(SolarTimeCorrection { longitude })
+    }
+
+    /// Birth longitude in degrees, east positive.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Apparent solar time at the birth longitude for the instant `dt`.
+    pub fn solar_time<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> NaiveDateTime {
+        let utc = dt.naive_utc();
+        let minutes = self.longitude * 4.0 + equation_of_time_minutes(utc);
+        utc + Duration::milliseconds((minutes * 60_000.0).round() as i64)
+    }
+
+    /// Difference between solar time and the civil clock of `dt`, in seconds.
+    pub fn offset_seconds<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> i64 {
+        (self.solar_time(dt) - dt.naive_local()).num_seconds()
+    }
+}
+
+/// Equation of time (apparent minus mean solar time) in minutes for a UTC instant.
+///
+/// Spencer's Fourier series (as used by NOAA), accurate to about half a minute.
+pub fn equation_of_time_minutes(utc: NaiveDateTime) -> f64 {
+    let days_in_year = if utc.date().leap_year() { 366.0 } else { 365.0 };
+    let hour = utc.hour() as f64 + utc.minute() as f64 / 60.0;
+    let gamma = 2.0 * PI / days_in_year * (utc.ordinal0() as f64 + (hour - 12.0) / 24.0);
+    229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn equation_of_time_extremes() {
+        // About -14.2 min in mid February and +16.4 min in early November.
+        let feb = equation_of_time_minutes(utc("2024-02-11 12:00:00"));
+        let nov = equation_of_time_minutes(utc("2024-11-03 12:00:00"));
+        assert!((feb + 14.2).abs() < 0.5, "{feb}");
+        assert!((nov - 16.4).abs() < 0.5, "{nov}");
+    }
+
+    #[test]
+    fn longitude_bounds() {
+        assert!(SolarTimeCorrection::new(116.4).is_some());
+        assert!(SolarTimeCorrection::new(-180.0).is_some());
+        assert!(SolarTimeCorrection::new(181.0).is_none());
+        assert!(SolarTimeCorrection::new(f64::NAN).is_none());
+    }
+}
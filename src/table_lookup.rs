@@ -1,4 +1,5 @@
 use crate::data::{BAZI_YEAR_DATA, BaziYearData};
+use crate::error::LunarError;
 
 pub const MIN_SUPPORTED_YEAR: i32 = 1900;
 pub const MAX_SUPPORTED_YEAR: i32 = 2099;
@@ -42,7 +43,7 @@ const NA_YIN: [&str; 30] = [
 
 /// Converts Gregorian year to a 0..=59 cycle index where 1984 is 甲子 (index 0).
 pub fn get_cycle_index(year: i32) -> usize {
-    (year - 1984).rem_euclid(60) as usize
+    (i64::from(year) - 1984).rem_euclid(60) as usize
 }
 
 /// Returns (Heavenly Stem, Earthly Branch) for a 0..=59 cycle index.
@@ -68,15 +69,28 @@ pub fn get_nayin_by_year(year: i32) -> &'static str {
     NA_YIN[idx / 2]
 }
 
-/// Find the year data from the static table.
-pub fn get_year_data(year: i32) -> Option<&'static BaziYearData> {
+/// Returns the table index of `year`, or `OutOfRange` outside `MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR`.
+pub(crate) fn table_index(year: i32) -> Result<usize, LunarError> {
     if !(MIN_SUPPORTED_YEAR..=MAX_SUPPORTED_YEAR).contains(&year) {
-        return None;
+        return Err(LunarError::OutOfRange {
+            year,
+            min: MIN_SUPPORTED_YEAR,
+            max: MAX_SUPPORTED_YEAR,
+        });
     }
-    let idx = (year - MIN_SUPPORTED_YEAR) as usize;
-    let row = BAZI_YEAR_DATA.get(idx)?;
+    Ok((year - MIN_SUPPORTED_YEAR) as usize)
+}
+
+/// Find the year data from the static table.
+pub fn get_year_data(year: i32) -> Option<&'static BaziYearData> {
+    try_get_year_data(year).ok()
+}
+
+/// Like [`get_year_data`], but explains why a year is unavailable.
+pub fn try_get_year_data(year: i32) -> Result<&'static BaziYearData, LunarError> {
+    let row = &BAZI_YEAR_DATA[table_index(year)?];
     debug_assert_eq!(row.year, year);
-    Some(row)
+    Ok(row)
 }
 
 #[cfg(test)]
@@ -88,6 +102,8 @@ mod tests {
         assert_eq!(get_cycle_index(1984), 0);
         assert_eq!(get_cycle_index(1985), 1);
         assert_eq!(get_cycle_index(1983), 59);
+        assert_eq!(get_cycle_index(i32::MIN), 48);
+        assert_eq!(get_cycle_index(i32::MAX), 3);
     }
 
     #[test]
@@ -97,4 +113,16 @@ mod tests {
         assert_eq!(get_year_data(1900).map(|y| y.year), Some(1900));
         assert_eq!(get_year_data(2099).map(|y| y.year), Some(2099));
     }
+
+    #[test]
+    fn year_lookup_reports_range() {
+        assert_eq!(
+            try_get_year_data(2100).map(|y| y.year),
+            Err(LunarError::OutOfRange {
+                year: 2100,
+                min: 1900,
+                max: 2099
+            })
+        );
+    }
 }
@@ -0,0 +1,182 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{GanZhi, HeavenlyStem};
+use crate::hidden_stems::hidden_stems;
+use std::fmt;
+
+/// The Ten Gods (十神): a stem's relation to the day master.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TenGod {
+    /// 比肩: same element, same polarity.
+    BiJian,
+    /// 劫财: same element, opposite polarity.
+    JieCai,
+    /// 食神: produced by the day master, same polarity.
+    ShiShen,
+    /// 伤官: produced by the day master, opposite polarity.
+    ShangGuan,
+    /// 偏财: overcome by the day master, same polarity.
+    PianCai,
+    /// 正财: overcome by the day master, opposite polarity.
+    ZhengCai,
+    /// 七杀: overcomes the day master, same polarity.
+    QiSha,
+    /// 正官: overcomes the day master, opposite polarity.
+    ZhengGuan,
+    /// 偏印: produces the day master, same polarity.
+    PianYin,
+    /// 正印: produces the day master, opposite polarity.
+    ZhengYin,
+}
+
+impl TenGod {
+    /// Ten God of `stem` relative to `day_master`.
+    pub fn of(day_master: HeavenlyStem, stem: HeavenlyStem) -> TenGod {
+        let me = day_master.element();
+        let other = stem.element();
+        let same_polarity = day_master.yin_yang() == stem.yin_yang();
+        let (same, opposite) = if other == me {
+            (TenGod::BiJian, TenGod::JieCai)
+        } else if me.generates() == other {
+            (TenGod::ShiShen, TenGod::ShangGuan)
+        } else if me.overcomes() == other {
+            (TenGod::PianCai, TenGod::ZhengCai)
+        } else if other.overcomes() == me {
+            (TenGod::QiSha, TenGod::ZhengGuan)
+        } else {
+            (TenGod::PianYin, TenGod::ZhengYin)
+        };
+        if same_polarity { same } else { opposite }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TenGod::BiJian => "比肩",
+            TenGod::JieCai => "劫财",
+            TenGod::ShiShen => "食神",
+            TenGod::ShangGuan => "伤官",
+            TenGod::PianCai => "偏财",
+            TenGod::ZhengCai => "正财",
+            TenGod::QiSha => "七杀",
+            TenGod::ZhengGuan => "正官",
+            TenGod::PianYin => "偏印",
+            TenGod::ZhengYin => "正印",
+        }
+    }
+}
+
+impl fmt::Display for TenGod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Ten Gods of one pillar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PillarTenGods {
+    pub pillar: GanZhi,
+    /// Ten God of the visible stem; `None` for the day stem itself (日主).
+    pub stem: Option<TenGod>,
+    /// Hidden stems of the branch with their Ten Gods, main qi first.
+    pub hidden: Vec<(HeavenlyStem, TenGod)>,
+}
+
+impl fmt::Display for PillarTenGods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.stem {
+            Some(god) => write!(f, "{} {}", self.pillar, god)?,
+            None => write!(f, "{} 日主", self.pillar)?,
+        }
+        f.write_str(" | 藏干")?;
+        for (stem, god) in &self.hidden {
+            write!(f, " {}({})", stem, god)?;
+        }
+        Ok(())
+    }
+}
+
+/// Ten Gods of a whole chart, relative to its day master.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartTenGods {
+    pub day_master: HeavenlyStem,
+    pub year: PillarTenGods,
+    pub month: PillarTenGods,
+    pub day: PillarTenGods,
+    pub hour: PillarTenGods,
+}
+
+fn pillar_ten_gods(day_master: HeavenlyStem, pillar: GanZhi, is_day: bool) -> PillarTenGods {
+    PillarTenGods {
+        pillar,
+        stem: (!is_day).then(|| TenGod::of(day_master, pillar.stem())),
+        hidden: hidden_stems(pillar.branch())
+            .iter()
+            .map(|h| (h.stem, TenGod::of(day_master, h.stem)))
+            .collect(),
+    }
+}
+
+/// Ten Gods for every visible and hidden stem of a chart.
+pub fn ten_gods(pillars: &BaziPillars) -> ChartTenGods {
+    let day_master = pillars.day.stem();
+    ChartTenGods {
+        day_master,
+        year: pillar_ten_gods(day_master, pillars.year, false),
+        month: pillar_ten_gods(day_master, pillars.month, false),
+        day: pillar_ten_gods(day_master, pillars.day, true),
+        hour: pillar_ten_gods(day_master, pillars.hour, false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use HeavenlyStem::*;
+
+    #[test]
+    fn ten_gods_for_jia_day_master() {
+        let expected = [
+            (Jia, TenGod::BiJian),
+            (Yi, TenGod::JieCai),
+            (Bing, TenGod::ShiShen),
+            (Ding, TenGod::ShangGuan),
+            (Wu, TenGod::PianCai),
+            (Ji, TenGod::ZhengCai),
+            (Geng, TenGod::QiSha),
+            (Xin, TenGod::ZhengGuan),
+            (Ren, TenGod::PianYin),
+            (Gui, TenGod::ZhengYin),
+        ];
+        for (stem, god) in expected {
+            assert_eq!(TenGod::of(Jia, stem), god, "甲 vs {stem}");
+        }
+        assert_eq!(TenGod::of(Gui, Wu), TenGod::ZhengGuan);
+        assert_eq!(TenGod::of(Xin, Ding), TenGod::QiSha);
+    }
+
+    #[test]
+    fn chart_ten_gods() {
+        // 乙丑年 庚辰月 庚寅日 庚辰时, day master 庚.
+        let pillars = BaziPillars {
+            year: "乙丑".parse().unwrap(),
+            month: "庚辰".parse().unwrap(),
+            day: "庚寅".parse().unwrap(),
+            hour: "庚辰".parse().unwrap(),
+        };
+        let chart = ten_gods(&pillars);
+        assert_eq!(chart.day_master, Geng);
+        assert_eq!(chart.year.stem, Some(TenGod::ZhengCai));
+        assert_eq!(chart.month.stem, Some(TenGod::BiJian));
+        assert_eq!(chart.day.stem, None);
+        assert_eq!(
+            chart.year.to_string(),
+            "乙丑 正财 | 藏干 己(正印) 癸(伤官) 辛(劫财)"
+        );
+        assert_eq!(
+            chart.day.to_string(),
+            "庚寅 日主 | 藏干 甲(偏财) 丙(七杀) 戊(偏印)"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::bazi_calc::BaziPillars;
+use crate::ganzhi::{EarthlyBranch, GanZhi};
+use crate::interactions::PillarPosition;
+use std::fmt;
+
+/// Void branches (旬空) of one key pillar and the chart branches they fall on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XunVoid {
+    /// Pillar whose 旬 is used: `Day` (日空) or `Year` (年空).
+    pub key: PillarPosition,
+    pub xun_head: GanZhi,
+    pub branches: [EarthlyBranch; 2],
+    /// Other pillars whose branch is void, in chart order.
+    pub marked: Vec<PillarPosition>,
+}
+
+impl fmt::Display for XunVoid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}柱 {}旬 空{}{}: ",
+            self.key, self.xun_head, self.branches[0], self.branches[1]
+        )?;
+        if self.marked.is_empty() {
+            return f.write_str("none");
+        }
+        let marked: Vec<String> = self.marked.iter().map(|p| format!("{p}支")).collect();
+        f.write_str(&marked.join(", "))
+    }
+}
+
+/// Void branches by the day pillar (first) and the year pillar (second), with
+/// the pillars each one marks.
+pub fn chart_voids(pillars: &BaziPillars) -> [XunVoid; 2] {
+    let chart = [
+        (PillarPosition::Year, pillars.year),
+        (PillarPosition::Month, pillars.month),
+        (PillarPosition::Day, pillars.day),
+        (PillarPosition::Hour, pillars.hour),
+    ];
+    [
+        (PillarPosition::Day, pillars.day),
+        (PillarPosition::Year, pillars.year),
+    ]
+    .map(|(key, pillar)| {
+        let branches = pillar.void_branches();
+        XunVoid {
+            key,
+            xun_head: pillar.xun_head(),
+            branches,
+            marked: chart
+                .iter()
+                .filter(|(_, gz)| branches.contains(&gz.branch()))
+                .map(|(position, _)| *position)
+                .collect(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_void_branches() {
+        // 丙寅 day (甲子旬) voids 戌亥; 癸亥 year (甲寅旬) voids 子丑.
+        let [day, year] = chart_voids(&"癸亥 甲子 丙寅 戊戌".parse().unwrap());
+        assert_eq!(day.to_string(), "日柱 甲子旬 空戌亥: 年支, 时支");
+        assert_eq!(year.to_string(), "年柱 甲寅旬 空子丑: 月支");
+    }
+
+    #[test]
+    fn no_void_in_chart() {
+        let [day, year] = chart_voids(&"乙丑 庚辰 庚寅 庚辰".parse().unwrap());
+        assert_eq!(day.to_string(), "日柱 甲申旬 空午未: none");
+        assert!(year.marked.is_empty());
+    }
+}
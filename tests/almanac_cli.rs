@@ -0,0 +1,25 @@
+//! `almanac` exit codes: 0 on success, 1 for dates outside the table, 2 for usage errors.
+
+use std::process::{Command, Stdio};
+
+fn almanac(args: &[&str]) -> Option<i32> {
+    Command::new(env!("CARGO_BIN_EXE_almanac"))
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap()
+        .code()
+}
+
+#[test]
+fn no_clash_years_outside_the_table_are_usage_errors() {
+    let range = ["2024-01-01", "--to", "2024-01-03", "--no-clash"];
+    for year in ["1985", "乙丑"] {
+        assert_eq!(almanac(&[&range[..], &[year]].concat()), Some(0), "{year}");
+    }
+    for year in ["-2147483648", "2147483647", "99999999999", "1899", "2100"] {
+        assert_eq!(almanac(&[&range[..], &[year]].concat()), Some(2), "{year}");
+    }
+    assert_eq!(almanac(&["1899-12-31"]), Some(1));
+}
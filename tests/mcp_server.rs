@@ -0,0 +1,216 @@
+//! `lunar-calendar-mcp`, driven through a pipe the way an MCP client does.
+
+mod common;
+
+use common::Pipe;
+use serde_json::{Value, json};
+
+/// Starts the server and completes the `initialize` handshake.
+fn connect() -> (Pipe, Value) {
+    let mut client = Pipe::spawn(env!("CARGO_BIN_EXE_lunar-calendar-mcp"), &[]);
+    let init = client.call(
+        0,
+        "initialize",
+        json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0" },
+        }),
+    );
+    client.send(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#);
+    (client, init)
+}
+
+fn call_tool(client: &mut Pipe, id: u64, name: &str, arguments: Value) -> Value {
+    let reply = client.call(
+        id,
+        "tools/call",
+        json!({ "name": name, "arguments": arguments }),
+    );
+    reply["result"].clone()
+}
+
+#[test]
+fn handshake_and_tool_schemas() {
+    let (mut client, init) = connect();
+    assert_eq!(init["result"]["protocolVersion"], "2025-06-18");
+    assert!(init["result"]["capabilities"]["tools"].is_object());
+    assert_eq!(init["result"]["serverInfo"]["name"], "lunar-calendar");
+
+    assert_eq!(client.call(1, "ping", json!({}))["result"], json!({}));
+
+    let list = client.call(2, "tools/list", json!({}));
+    let tools = list["result"]["tools"].as_array().unwrap();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    for name in [
+        "get_bazi_pillars",
+        "get_jieqi_context",
+        "lunar_to_gregorian",
+        "get_year_info",
+    ] {
+        assert!(names.contains(&name), "{name} is listed");
+    }
+    for tool in tools {
+        let schema = &tool["inputSchema"];
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["additionalProperties"], false);
+        for required in schema["required"].as_array().unwrap() {
+            assert!(schema["properties"][required.as_str().unwrap()].is_object());
+        }
+        assert!(!tool["description"].as_str().unwrap().is_empty());
+    }
+    let schema_of =
+        |name: &str| tools.iter().find(|t| t["name"] == name).unwrap()["inputSchema"].clone();
+    let year = schema_of("get_year_info");
+    assert_eq!(year["required"], json!(["year"]));
+    assert_eq!(year["properties"]["year"]["minimum"], 1900);
+    assert_eq!(year["properties"]["year"]["maximum"], 2099);
+    let pillars = schema_of("get_bazi_pillars");
+    assert_eq!(pillars["required"], json!(["datetime"]));
+    assert_eq!(
+        pillars["properties"]["zi_rule"]["enum"],
+        json!(["midnight", "late-zi", "23"])
+    );
+    let lunar = schema_of("lunar_to_gregorian");
+    assert_eq!(lunar["required"], json!(["year", "month", "day"]));
+    assert_eq!(lunar["properties"]["is_leap"]["type"], "boolean");
+
+    client.finish();
+}
+
+#[test]
+fn tools_answer_with_structured_content() {
+    let (mut client, _) = connect();
+
+    let pillars = call_tool(
+        &mut client,
+        1,
+        "get_bazi_pillars",
+        json!({ "datetime": "1985-04-21 08:00" }),
+    );
+    assert_eq!(pillars["isError"], false);
+    let chart = &pillars["structuredContent"];
+    assert_eq!(chart["year"]["ganzhi"], "乙丑");
+    assert_eq!(chart["day"]["ganzhi"], "庚寅");
+    let text: Value =
+        serde_json::from_str(pillars["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(&text, chart);
+
+    let ctx = call_tool(
+        &mut client,
+        2,
+        "get_jieqi_context",
+        json!({ "datetime": "2024-02-04T08:27:06Z" }),
+    );
+    assert_eq!(ctx["structuredContent"]["next"]["name"], "立春");
+    assert_eq!(ctx["structuredContent"]["next"]["remaining_seconds"], 1);
+
+    let leap = call_tool(
+        &mut client,
+        3,
+        "lunar_to_gregorian",
+        json!({ "year": 2023, "month": 2, "day": 1, "is_leap": true }),
+    );
+    assert_eq!(leap["structuredContent"]["date"], "2023-03-22");
+    let plain = call_tool(
+        &mut client,
+        4,
+        "lunar_to_gregorian",
+        json!({ "year": 2023, "month": 2, "day": 1 }),
+    );
+    assert_eq!(plain["structuredContent"]["date"], "2023-02-20");
+
+    let back = call_tool(
+        &mut client,
+        5,
+        "gregorian_to_lunar",
+        json!({ "date": "2023-03-22" }),
+    );
+    assert_eq!(back["structuredContent"]["text"], "2023年闰二月初一");
+
+    let info = call_tool(&mut client, 6, "get_year_info", json!({ "year": 2026 }));
+    let info = &info["structuredContent"];
+    assert_eq!(info["lunar_new_year"], "2026-02-17");
+    assert_eq!(info["pillar"]["ganzhi"], "丙午");
+    assert_eq!(info["pillar"]["nayin"], "天河水");
+    assert_eq!(info["pillar"]["zodiac"], "马");
+
+    client.finish();
+}
+
+#[test]
+fn bad_arguments_are_tool_errors() {
+    let (mut client, _) = connect();
+
+    let missing = call_tool(&mut client, 1, "get_bazi_pillars", json!({}));
+    assert_eq!(missing["isError"], true);
+    assert!(
+        missing["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("datetime")
+    );
+
+    for (id, arguments) in (2..).zip([
+        json!({ "year": 1899 }),
+        json!({ "year": "2024" }),
+        json!({ "year": 2024, "month": 1 }),
+    ]) {
+        let result = call_tool(&mut client, id, "get_year_info", arguments);
+        assert_eq!(result["isError"], true);
+    }
+    let zi = call_tool(
+        &mut client,
+        5,
+        "get_bazi_pillars",
+        json!({ "datetime": "2024-01-01", "zi_rule": "noon" }),
+    );
+    assert_eq!(zi["isError"], true);
+
+    // In range for the schema, but 2023 has no leap fourth month.
+    let no_leap = call_tool(
+        &mut client,
+        6,
+        "lunar_to_gregorian",
+        json!({ "year": 2023, "month": 4, "day": 1, "is_leap": true }),
+    );
+    assert_eq!(no_leap["isError"], true);
+    assert!(no_leap.get("structuredContent").is_none());
+
+    let unknown = client.call(
+        7,
+        "tools/call",
+        json!({ "name": "moon_phase", "arguments": {} }),
+    );
+    assert_eq!(unknown["error"]["code"], -32602);
+    let method = client.call(8, "resources/list", json!({}));
+    assert_eq!(method["error"]["code"], -32601);
+
+    client.finish();
+}
+
+#[test]
+fn framing_errors_get_json_rpc_replies() {
+    let (mut client, _) = connect();
+
+    for message in ["42", "\"ping\"", "[1]", r#"{"id":1,"method":"ping"}"#] {
+        client.send(message);
+        let reply = client.receive();
+        let reply = reply.as_array().and_then(|r| r.first()).unwrap_or(&reply);
+        assert_eq!(reply["error"]["code"], -32600, "{message}");
+    }
+    client.send("{ not json");
+    assert_eq!(client.receive()["error"]["code"], -32700);
+
+    client.send(
+        r#"[{"jsonrpc":"2.0","id":"a","method":"ping"},{"jsonrpc":"2.0","method":"notifications/cancelled"}]"#,
+    );
+    let batch = client.receive();
+    assert_eq!(batch.as_array().unwrap().len(), 1);
+    assert_eq!(batch[0]["id"], "a");
+
+    // The server is still answering after all of the above.
+    assert_eq!(client.call(1, "ping", json!({}))["result"], json!({}));
+
+    client.finish();
+}
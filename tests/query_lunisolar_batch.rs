@@ -0,0 +1,134 @@
+//! `query-lunisolar --batch`, fed through stdin and from a file.
+
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn batch(args: &[&str], stdin: impl AsRef<[u8]>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_query-lunisolar"))
+        .arg("--batch")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("query-lunisolar runs");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_ref())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn json_lines(output: &Output) -> Vec<Value> {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is JSON"))
+        .collect()
+}
+
+#[test]
+fn json_lines_match_single_queries() {
+    let output = batch(
+        &["--tz", "Asia/Shanghai"],
+        "2024-02-04 16:27\n\n# skipped\n2023-03-22 08:30\n",
+    );
+    let records = json_lines(&output);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["line"], 1);
+    assert_eq!(records[1]["line"], 4);
+    for record in &records {
+        let single = Command::new(env!("CARGO_BIN_EXE_query-lunisolar"))
+            .args([record["input"].as_str().unwrap(), "--tz", "Asia/Shanghai"])
+            .args(["--format", "json"])
+            .output()
+            .unwrap();
+        let single: Value = serde_json::from_slice(&single.stdout).unwrap();
+        assert_eq!(record["result"], single);
+        assert!(record["id"].is_null());
+    }
+    // LiChun 2024 is at 16:27:07, so 16:27 still belongs to 癸卯.
+    assert_eq!(records[0]["result"]["pillars"]["year"]["ganzhi"], "癸卯");
+}
+
+#[test]
+fn errors_are_reported_per_line() {
+    let output = batch(&[], "not-a-date\n1800-01-01\n2024-06-01\n");
+    let records = json_lines(&output);
+    assert_eq!(records.len(), 3);
+    assert!(
+        records[0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid input")
+    );
+    assert!(records[1]["error"].as_str().unwrap().contains("1800"));
+    assert!(records[0].get("result").is_none());
+    assert_eq!(records[2]["result"]["lunar_date"]["text"], "2024年四月廿五");
+}
+
+#[test]
+fn invalid_utf8_line_does_not_stop_the_batch() {
+    let output = batch(&[], b"2024-01-01\n\xff\xfe bad\n2024-02-10\n");
+    let records = json_lines(&output);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[1]["line"], 2);
+    assert!(records[1]["error"].as_str().unwrap().contains("UTF-8"));
+    assert_eq!(records[2]["result"]["lunar_date"]["text"], "2024年正月初一");
+}
+
+#[test]
+fn csv_file_with_ids_to_csv() {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("batch-input.csv");
+    std::fs::write(
+        &path,
+        "id,datetime\na1,2024-02-04 16:28\n\"b,2\",\"2023-03-22 08:30\"\nc3,bad\n",
+    )
+    .unwrap();
+    let output = batch(&[path.to_str().unwrap(), "--format", "csv"], "");
+    assert!(output.status.success());
+    let text = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<&str> = text.lines().collect();
+    assert_eq!(rows.len(), 4);
+    assert!(rows[0].starts_with("line,id,input,datetime,"));
+    assert!(rows[1].starts_with("2,a1,2024-02-04 16:28,2024-02-04T16:28:00+08:00,"));
+    assert!(rows[1].contains(",甲辰,丙寅,戊戌,"));
+    assert!(rows[1].ends_with(','), "no error column: {}", rows[1]);
+    assert!(rows[2].starts_with("3,\"b,2\",2023-03-22 08:30,"));
+    assert!(rows[2].contains("2023年闰二月初一"));
+    assert!(rows[3].starts_with("4,c3,bad,,"));
+    assert!(rows[3].ends_with("Use YYYY-MM-DD or YYYY-MM-DD HH:MM[:SS]."));
+}
+
+#[test]
+fn lunar_settings_apply_to_every_line() {
+    let output = batch(&["--lunar", "--leap"], "2023-02-01\n2023-02-30\n");
+    let records = json_lines(&output);
+    assert_eq!(
+        records[0]["result"]["input"]["datetime"],
+        "2023-03-22T00:00:00+08:00"
+    );
+    assert!(
+        records[1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid lunar date")
+    );
+}
+
+#[test]
+fn rejects_text_format_and_missing_file() {
+    assert_eq!(batch(&["--format", "text"], "").status.code(), Some(2));
+    assert_eq!(
+        batch(&["/nonexistent/batch.txt"], "").status.code(),
+        Some(2)
+    );
+}
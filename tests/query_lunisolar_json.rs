@@ -0,0 +1,190 @@
+//! Snapshot tests for `query-lunisolar --format json`.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1` to rewrite `tests/snapshots/*.json` after an
+//! intended change (and bump the schema version if the change is breaking).
+
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn query(args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_query-lunisolar"))
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .expect("query-lunisolar runs");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("stdout is JSON")
+}
+
+fn assert_snapshot(name: &str, actual: &Value) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.json"));
+    let rendered = serde_json::to_string_pretty(actual).unwrap() + "\n";
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, rendered).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {e} (run with UPDATE_SNAPSHOTS=1)", path.display()));
+    assert_eq!(rendered, expected, "snapshot {name} differs");
+}
+
+fn schema() -> Value {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("schema/query-lunisolar.schema.json");
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Checks the subset of JSON Schema the schema file uses: `type`, `const`,
+/// `enum`, `minimum`, `maximum`, `required`, `properties`, `additionalProperties: false`, `items`,
+/// `oneOf` and local `$ref`s.
+fn validate(root: &Value, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(target) = schema["$ref"].as_str() {
+        let name = target.strip_prefix("#/$defs/").unwrap();
+        return validate(root, &root["$defs"][name], value, path);
+    }
+    if let Some(options) = schema["oneOf"].as_array() {
+        let matching = options
+            .iter()
+            .filter(|option| validate(root, option, value, path).is_ok())
+            .count();
+        if matching != 1 {
+            return Err(format!("{path}: {matching} oneOf branches match {value}"));
+        }
+        return Ok(());
+    }
+    let type_ok = |name: &Value| match name.as_str().unwrap() {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        other => panic!("unsupported type {other}"),
+    };
+    let type_matches = match &schema["type"] {
+        Value::Null => true,
+        Value::Array(names) => names.iter().any(type_ok),
+        name => type_ok(name),
+    };
+    if !type_matches {
+        return Err(format!(
+            "{path}: expected type {}, got {value}",
+            schema["type"]
+        ));
+    }
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        return Err(format!("{path}: expected {expected}, got {value}"));
+    }
+    if let Some(allowed) = schema["enum"].as_array()
+        && !allowed.contains(value)
+    {
+        return Err(format!("{path}: {value} not in {allowed:?}"));
+    }
+    if let Some(n) = value.as_i64() {
+        let below = schema["minimum"].as_i64().is_some_and(|min| n < min);
+        let above = schema["maximum"].as_i64().is_some_and(|max| n > max);
+        if below || above {
+            return Err(format!("{path}: {n} out of bounds"));
+        }
+    }
+    if let Some(object) = value.as_object() {
+        for key in schema["required"].as_array().into_iter().flatten() {
+            if !object.contains_key(key.as_str().unwrap()) {
+                return Err(format!("{path}: missing {key}"));
+            }
+        }
+        for (key, field) in object {
+            let field_path = format!("{path}.{key}");
+            match schema["properties"].get(key) {
+                Some(field_schema) => validate(root, field_schema, field, &field_path)?,
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    return Err(format!("{field_path}: not in schema"));
+                }
+                None => {}
+            }
+        }
+    }
+    if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+        for (i, item) in values.iter().enumerate() {
+            validate(root, items, item, &format!("{path}[{i}]"))?;
+        }
+    }
+    Ok(())
+}
+
+fn assert_matches_schema(value: &Value) {
+    let schema = schema();
+    if let Err(e) = validate(&schema, &schema, value, "$") {
+        panic!("{e}");
+    }
+}
+
+#[test]
+fn gregorian_input_in_table_frame() {
+    let report = query(&["2026-02-04 00:00:00"]);
+    assert_matches_schema(&report);
+    assert_snapshot("gregorian_2026-02-04", &report);
+}
+
+#[test]
+fn lunar_leap_input_in_named_zone() {
+    let report = query(&[
+        "2023-02-01 08:30",
+        "--lunar",
+        "--leap",
+        "--tz",
+        "America/New_York",
+        "--zi-rule",
+        "23",
+    ]);
+    assert_matches_schema(&report);
+    assert_eq!(report["lunar_date"]["is_leap"], true);
+    assert_eq!(report["input"]["utc_offset_seconds"], -4 * 3600);
+    assert_snapshot("lunar_leap_2023-02-01", &report);
+}
+
+#[test]
+fn table_edge_has_null_parts() {
+    let report = query(&["2099-12-31 12:00"]);
+    assert_matches_schema(&report);
+    assert!(report["jieqi"].is_null());
+    assert!(report["lunar_date"].is_object());
+    assert_snapshot("table_edge_2099-12-31", &report);
+}
+
+#[test]
+fn schema_version_matches_schema_file() {
+    let report = query(&["2024-06-01"]);
+    assert_eq!(
+        report["schema_version"],
+        schema()["properties"]["schema_version"]["const"]
+    );
+}
+
+#[test]
+fn schema_check_catches_drift() {
+    let schema = schema();
+    let mut report = query(&["2024-06-01"]);
+    report["pillars"]["day"]["stem"]["pinyin"] = "jia".into();
+    assert!(validate(&schema, &schema, &report, "$").is_err());
+    report["pillars"] = Value::Null;
+    report["lunar_date"]["month"] = 13.into();
+    assert!(validate(&schema, &schema, &report, "$").is_err());
+}
+
+#[test]
+fn rejects_unknown_format() {
+    let output = Command::new(env!("CARGO_BIN_EXE_query-lunisolar"))
+        .args(["2024-06-01", "--format", "yaml"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,85 @@
+//! `lunar-calendar serve --stdio`, driven through a pipe one request at a time.
+
+mod common;
+
+use common::Pipe;
+use serde_json::json;
+use std::process::{Command, Stdio};
+
+fn start() -> Pipe {
+    Pipe::spawn(env!("CARGO_BIN_EXE_lunar-calendar"), &["serve", "--stdio"])
+}
+
+#[test]
+fn every_method_over_one_connection() {
+    let mut server = start();
+
+    let pillars = server.call(1, "pillars", json!({ "datetime": "1985-04-21 08:00" }));
+    assert_eq!(pillars["result"]["year"]["ganzhi"], "乙丑");
+    assert_eq!(pillars["result"]["hour"]["branch"]["name"], "辰");
+
+    let ctx = server.call(
+        2,
+        "jieqi_context",
+        json!({ "datetime": "2024-02-04T08:27:06Z" }),
+    );
+    assert_eq!(ctx["result"]["next"]["name"], "立春");
+    assert_eq!(ctx["result"]["next"]["remaining_seconds"], 1);
+
+    let row = server.call(3, "year_data", json!({ "year": 2024 }));
+    assert_eq!(row["result"]["lunar_new_year"], "2024-02-10");
+    assert_eq!(row["result"]["jieqi"].as_array().unwrap().len(), 24);
+
+    let lunar = server.call(4, "lunar_date", json!({ "date": "2024-02-10" }));
+    assert_eq!(lunar["result"]["text"], "2024年正月初一");
+
+    let nayin = server.call(5, "nayin", json!({ "ganzhi": "丙午" }));
+    assert_eq!(nayin["result"]["nayin"], "天河水");
+
+    server.finish();
+}
+
+#[test]
+fn errors_keep_the_server_running() {
+    let mut server = start();
+
+    server.send("this is not json");
+    let parse = server.receive();
+    assert_eq!(parse["error"]["code"], -32700);
+
+    let unknown = server.call(1, "moon_phase", json!({}));
+    assert_eq!(unknown["error"]["code"], -32601);
+
+    let outside = server.call(2, "year_data", json!({ "year": 1899 }));
+    assert_eq!(outside["error"]["code"], -32000);
+
+    // A notification is not answered; the next reply belongs to id 3.
+    server.send(r#"{"jsonrpc":"2.0","method":"nayin","params":{"year":2024}}"#);
+    let next = server.call(3, "nayin", json!({ "year": 2026 }));
+    assert_eq!(next["result"]["ganzhi"], "丙午");
+
+    // Extreme years must not overflow; the server keeps answering after them.
+    let lowest = server.call(4, "nayin", json!({ "year": i32::MIN }));
+    assert_eq!(lowest["result"]["cycle_index"], 48);
+    let after = server.call(5, "nayin", json!({ "year": 2024 }));
+    assert_eq!(after["result"]["nayin"], "覆灯火");
+
+    server.send(
+        r#"[{"jsonrpc":"2.0","id":"a","method":"nayin","params":{"year":1984}},{"jsonrpc":"2.0","id":"b","method":"lunar_date","params":{"date":"bad"}}]"#,
+    );
+    let batch = server.receive();
+    assert_eq!(batch[0]["result"]["nayin"], "海中金");
+    assert_eq!(batch[1]["error"]["code"], -32602);
+
+    server.finish();
+}
+
+#[test]
+fn rejects_unknown_subcommand() {
+    let status = Command::new(env!("CARGO_BIN_EXE_lunar-calendar"))
+        .args(["serve", "--tcp"])
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(2));
+}